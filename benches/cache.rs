@@ -0,0 +1,112 @@
+//! Compares importing a large model with `Importer::read_file` to loading it from a cache.
+//!
+//! Run with `cargo bench --bench cache`. The model is a grid of quads, written to an OBJ file in
+//! the temporary directory.
+
+extern crate assimp;
+
+use std::env;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::ptr;
+use std::slice;
+use std::time::{Duration, Instant};
+
+use assimp::Importer;
+use assimp::cache::{self, CacheKey};
+
+// The number of quads along each side of the grid
+const GRID_SIZE: usize = 400;
+const ITERATIONS: u32 = 5;
+
+fn write_grid(path: &str) {
+    let mut file = BufWriter::new(File::create(path).unwrap());
+    for y in 0..GRID_SIZE + 1 {
+        for x in 0..GRID_SIZE + 1 {
+            let u = x as f32 / GRID_SIZE as f32;
+            let v = y as f32 / GRID_SIZE as f32;
+            let height = (u * 20.0).sin() * (v * 20.0).cos() * 0.05;
+            writeln!(file, "v {} {} {}", u, height, v).unwrap();
+            writeln!(file, "vt {} {}", u, v).unwrap();
+        }
+    }
+
+    let row = GRID_SIZE + 1;
+    for y in 0..GRID_SIZE {
+        for x in 0..GRID_SIZE {
+            let i = y * row + x + 1;
+            writeln!(file, "f {0}/{0} {1}/{1} {2}/{2} {3}/{3}", i, i + 1, i + row + 1, i + row)
+                .unwrap();
+        }
+    }
+}
+
+// Returns the average time taken by `f`, which returns the number of meshes it loaded
+fn time<F: FnMut() -> usize>(mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        assert!(f() > 0);
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn millis(time: Duration) -> f64 {
+    time.as_secs() as f64 * 1000.0 + time.subsec_nanos() as f64 / 1_000_000.0
+}
+
+fn report(name: &str, time: Duration, import: Duration) {
+    println!("{:<18} {:>10.2} ms {:>10.1}x", name, millis(time), millis(import) / millis(time));
+}
+
+fn main() {
+    let dir = env::temp_dir().join("assimp-rs-bench-cache");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let model = dir.join("grid.obj");
+    let model = model.to_str().unwrap();
+    let cache_file = dir.join("grid.cache");
+    let cache_file = cache_file.to_str().unwrap();
+    write_grid(model);
+
+    let mut importer = Importer::new();
+    importer.triangulate(true);
+    importer.generate_normals(|x| {
+        x.enable = true;
+        x.smooth = true;
+    });
+    importer.join_identical_vertices(true);
+    importer.calc_tangent_space(|x| x.enable = true);
+
+    let import = time(|| importer.read_file(model).unwrap().num_meshes() as usize);
+
+    // The first call imports the file and writes the cache, later calls load it
+    let scene = cache::read_file_cached(&importer, model, cache_file).unwrap();
+    let cached = time(|| {
+        cache::read_file_cached(&importer, model, cache_file).unwrap().meshes.len()
+    });
+
+    // Copy the cache to an aligned buffer, as a memory map of the file would be
+    let data = fs::read(cache_file).unwrap();
+    let mut buffer = vec![0u64; (data.len() + 7) / 8];
+    unsafe {
+        ptr::copy_nonoverlapping(data.as_ptr(), buffer.as_mut_ptr() as *mut u8, data.len());
+    }
+    let data = unsafe { slice::from_raw_parts(buffer.as_ptr() as *const u8, data.len()) };
+    let dependencies = cache::cache_dependencies(data).unwrap();
+    let key = CacheKey::from_files(model, &dependencies, &importer).unwrap();
+    let loaded = time(|| cache::load_cache(data, key).unwrap().meshes.len());
+    let viewed = time(|| cache::view_cache(data, key).unwrap().meshes.len());
+
+    let vertices: usize = scene.meshes.iter().map(|x| x.vertices.len()).sum();
+    let faces: usize = scene.meshes.iter().map(|x| x.faces.len()).sum();
+    println!("{} vertices, {} faces, source {} KB, cache {} KB",
+             vertices,
+             faces,
+             fs::metadata(model).unwrap().len() / 1024,
+             data.len() / 1024);
+    report("read_file", import, import);
+    report("read_file_cached", cached, import);
+    report("load_cache", loaded, import);
+    report("view_cache", viewed, import);
+    fs::remove_dir_all(&dir).unwrap();
+}
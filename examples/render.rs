@@ -26,8 +26,8 @@ fn main() {
 
     // Setup logging
     LogStream::set_verbose_logging(true);
-    let mut log_stream = LogStream::stdout();
-    log_stream.attach();
+    let log_stream = LogStream::stdout();
+    let _log_guard = log_stream.attach().unwrap();
 
     // Load shaders
     let program = program!(&display,
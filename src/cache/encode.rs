@@ -0,0 +1,374 @@
+//! Binary encoding of the owned scene model.
+//!
+//! All values are little-endian. Strings and arrays are stored as a `u64` length followed by
+//! their contents, and enums as a `u32` tag followed by any data.
+//!
+//! Arrays of plain data, e.g. vertices, faces and animation keys, are padded after their length
+//! so the first element starts at a multiple of 8 bytes from the start of the cache. Their
+//! elements are stored the same way as they're laid out in memory on little-endian targets, see
+//! `Pod`, so the arrays can be used without copying them.
+
+use std::mem;
+use std::ptr;
+use std::slice;
+use std::str;
+
+use math::{Color3D, Color4D, Matrix4x4, Quaternion, Vector3D};
+use owned::*;
+
+use super::error::CacheError;
+
+/// The alignment of plain data arrays, relative to the start of the cache.
+const ALIGNMENT: usize = 8;
+
+pub trait Encode: Sized {
+    fn encode(&self, out: &mut Vec<u8>);
+    fn decode(input: &mut Reader) -> Result<Self, CacheError>;
+
+    // Arrays are stored element by element, plain data types override these with
+    // `pod_array_methods!`
+    fn encode_array(items: &[Self], out: &mut Vec<u8>) {
+        (items.len() as u64).encode(out);
+        for item in items {
+            item.encode(out);
+        }
+    }
+
+    fn decode_array(input: &mut Reader) -> Result<Vec<Self>, CacheError> {
+        let len = try!(input.len());
+        let mut items = Vec::with_capacity(len);
+        for _ in 0..len {
+            items.push(try!(Self::decode(input)));
+        }
+        Ok(items)
+    }
+}
+
+/// A plain data type, whose arrays are stored exactly as they're laid out in memory on
+/// little-endian targets.
+///
+/// # Safety
+/// The type must be `#[repr(C)]` and accept any bit pattern. Its fields are encoded in order,
+/// followed by zero bytes up to the size of the type, so there must be no padding between them.
+pub unsafe trait Pod: Encode + Copy {}
+
+/// Reads values from a byte slice, failing if the data is too short.
+pub struct Reader<'a> {
+    data: &'a [u8],
+    // The position of `data` from the start of the cache
+    offset: usize
+}
+
+impl<'a> Reader<'a> {
+    /// Reads `data`, which starts `offset` bytes from the start of the cache.
+    pub fn new(data: &'a [u8], offset: usize) -> Reader<'a> {
+        Reader { data: data, offset: offset }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], CacheError> {
+        if len > self.data.len() {
+            return Err(CacheError::InvalidFormat);
+        }
+        let (bytes, rest) = self.data.split_at(len);
+        self.data = rest;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    /// Reads a plain data array, returning the number of elements and their bytes.
+    fn pod_array<T: Pod>(&mut self) -> Result<(usize, &'a [u8]), CacheError> {
+        let len = try!(u64::decode(self));
+        let padding = padding(self.offset);
+        try!(self.take(padding));
+        match len.checked_mul(mem::size_of::<T>() as u64) {
+            Some(size) if size <= self.data.len() as u64 => {
+                Ok((len as usize, try!(self.take(size as usize))))
+            }
+            _ => Err(CacheError::InvalidFormat)
+        }
+    }
+
+    /// Reads a plain data array in place.
+    ///
+    /// Fails if the target is big-endian, or the array isn't aligned in memory because the cache
+    /// data isn't aligned.
+    pub fn slice<T: Pod>(&mut self) -> Result<&'a [T], CacheError> {
+        let (len, bytes) = try!(self.pod_array::<T>());
+        if cfg!(target_endian = "big") || bytes.as_ptr() as usize % mem::align_of::<T>() != 0 {
+            return Err(CacheError::NotMappable);
+        }
+        Ok(unsafe { slice::from_raw_parts(bytes.as_ptr() as *const T, len) })
+    }
+
+    /// Reads a string in place.
+    pub fn str(&mut self) -> Result<&'a str, CacheError> {
+        let len = try!(self.len());
+        let bytes = try!(self.take(len));
+        str::from_utf8(bytes).map_err(|_| CacheError::InvalidFormat)
+    }
+
+    // Every element takes at least one byte, so a length longer than the remaining data is
+    // invalid. This prevents allocating huge vectors for corrupt lengths.
+    pub fn len(&mut self) -> Result<usize, CacheError> {
+        let len = try!(u64::decode(self));
+        if len > self.data.len() as u64 {
+            return Err(CacheError::InvalidFormat);
+        }
+        Ok(len as usize)
+    }
+}
+
+/// Returns the number of bytes needed after `offset` to reach the alignment of plain data arrays.
+fn padding(offset: usize) -> usize {
+    (ALIGNMENT - offset % ALIGNMENT) % ALIGNMENT
+}
+
+// `out` contains the whole cache, so its length is the offset from the start of the cache
+fn encode_pod_array<T: Pod>(items: &[T], out: &mut Vec<u8>) {
+    (items.len() as u64).encode(out);
+    let len = out.len() + padding(out.len());
+    out.resize(len, 0);
+    out.reserve(items.len() * mem::size_of::<T>());
+    for item in items {
+        let end = out.len() + mem::size_of::<T>();
+        item.encode(out);
+        out.resize(end, 0);
+    }
+}
+
+fn decode_pod_array<T: Pod>(input: &mut Reader) -> Result<Vec<T>, CacheError> {
+    let (len, bytes) = try!(input.pod_array::<T>());
+    let mut items = Vec::with_capacity(len);
+    if cfg!(target_endian = "little") {
+        // The elements are stored as they're laid out in memory, so can be copied at once
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), items.as_mut_ptr() as *mut u8, bytes.len());
+            items.set_len(len);
+        }
+    } else {
+        for element in bytes.chunks(mem::size_of::<T>()) {
+            items.push(try!(T::decode(&mut Reader::new(element, 0))));
+        }
+    }
+    Ok(items)
+}
+
+macro_rules! pod_array_methods {
+    () => (
+        fn encode_array(items: &[Self], out: &mut Vec<u8>) {
+            encode_pod_array(items, out);
+        }
+
+        fn decode_array(input: &mut Reader) -> Result<Vec<Self>, CacheError> {
+            decode_pod_array(input)
+        }
+    )
+}
+
+macro_rules! impl_encode_int {
+    ($ty:ty) => (
+        impl Encode for $ty {
+            fn encode(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn decode(input: &mut Reader) -> Result<$ty, CacheError> {
+                let mut bytes = [0; mem::size_of::<$ty>()];
+                bytes.copy_from_slice(try!(input.take(mem::size_of::<$ty>())));
+                Ok(<$ty>::from_le_bytes(bytes))
+            }
+
+            pod_array_methods!();
+        }
+
+        unsafe impl Pod for $ty {}
+    )
+}
+
+impl_encode_int!(u8);
+impl_encode_int!(u32);
+impl_encode_int!(i32);
+impl_encode_int!(u64);
+
+impl Encode for f32 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.to_bits().encode(out);
+    }
+
+    fn decode(input: &mut Reader) -> Result<f32, CacheError> {
+        Ok(f32::from_bits(try!(u32::decode(input))))
+    }
+
+    pod_array_methods!();
+}
+
+unsafe impl Pod for f32 {}
+
+impl Encode for f64 {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.to_bits().encode(out);
+    }
+
+    fn decode(input: &mut Reader) -> Result<f64, CacheError> {
+        Ok(f64::from_bits(try!(u64::decode(input))))
+    }
+
+    pod_array_methods!();
+}
+
+unsafe impl Pod for f64 {}
+
+impl Encode for String {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (self.len() as u64).encode(out);
+        out.extend_from_slice(self.as_bytes());
+    }
+
+    fn decode(input: &mut Reader) -> Result<String, CacheError> {
+        input.str().map(|x| x.to_owned())
+    }
+}
+
+impl<T: Encode> Encode for Vec<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        T::encode_array(self, out);
+    }
+
+    fn decode(input: &mut Reader) -> Result<Vec<T>, CacheError> {
+        T::decode_array(input)
+    }
+}
+
+// Math types are stored as their components, in the same order as the Assimp structs. Some of
+// the structs are packed, so fields are copied before encoding them.
+macro_rules! impl_encode_math {
+    ($name:ident { $($field:ident),* }) => (
+        impl Encode for $name {
+            fn encode(&self, out: &mut Vec<u8>) {
+                $({ self.$field }.encode(out);)*
+            }
+
+            fn decode(input: &mut Reader) -> Result<$name, CacheError> {
+                $(let $field = try!(f32::decode(input));)*
+                Ok($name::new($($field),*))
+            }
+
+            pod_array_methods!();
+        }
+
+        unsafe impl Pod for $name {}
+    )
+}
+
+impl_encode_math!(Vector3D { x, y, z });
+impl_encode_math!(Color3D { r, g, b });
+impl_encode_math!(Color4D { r, g, b, a });
+impl_encode_math!(Quaternion { w, x, y, z });
+impl_encode_math!(Matrix4x4 { a1, a2, a3, a4, b1, b2, b3, b4, c1, c2, c3, c4, d1, d2, d3, d4 });
+
+// Fields are decoded in the order they're listed, as struct expressions are evaluated in order.
+// Structs prefixed with `pod` implement `Pod`, and must list all of their fields.
+macro_rules! impl_encode_struct {
+    (@methods $name:ident { $($field:ident),* }) => (
+        fn encode(&self, out: &mut Vec<u8>) {
+            $(self.$field.encode(out);)*
+        }
+
+        fn decode(input: &mut Reader) -> Result<$name, CacheError> {
+            Ok($name { $($field: try!(Encode::decode(input))),* })
+        }
+    );
+    (pod $name:ident { $($field:ident),* }) => (
+        impl Encode for $name {
+            impl_encode_struct!(@methods $name { $($field),* });
+            pod_array_methods!();
+        }
+
+        unsafe impl Pod for $name {}
+    );
+    ($name:ident { $($field:ident),* }) => (
+        impl Encode for $name {
+            impl_encode_struct!(@methods $name { $($field),* });
+        }
+    )
+}
+
+impl_encode_struct!(OwnedScene {
+    flags, root_node, meshes, materials, animations, textures, lights, cameras
+});
+impl_encode_struct!(OwnedNode { name, transformation, meshes, children });
+impl_encode_struct!(OwnedMesh {
+    name, primitive_types, vertices, normals, tangents, bitangents, colors, texture_coords,
+    num_uv_components, faces, bones, material_index, anim_meshes
+});
+impl_encode_struct!(OwnedBone { name, weights, offset_matrix });
+impl_encode_struct!(pod OwnedVertexWeight { vertex_id, weight });
+impl_encode_struct!(OwnedAnimMesh {
+    vertices, normals, tangents, bitangents, colors, texture_coords
+});
+impl_encode_struct!(OwnedMaterial { properties });
+impl_encode_struct!(OwnedMaterialProperty { key, semantic, index, data });
+impl_encode_struct!(OwnedAnimation {
+    name, duration, ticks_per_second, channels, mesh_channels
+});
+impl_encode_struct!(OwnedNodeAnim {
+    node_name, position_keys, rotation_keys, scaling_keys, pre_state, post_state
+});
+impl_encode_struct!(OwnedMeshAnim { name, keys });
+impl_encode_struct!(pod OwnedVectorKey { time, value });
+impl_encode_struct!(pod OwnedQuatKey { time, value });
+impl_encode_struct!(pod OwnedMeshKey { time, value });
+impl_encode_struct!(OwnedTexture { width, height, format_hint, data });
+impl_encode_struct!(OwnedLight {
+    name, light_type, position, direction, attenuation_constant, attenuation_linear,
+    attenuation_quadratic, color_diffuse, color_specular, color_ambient, angle_inner_cone,
+    angle_outer_cone
+});
+impl_encode_struct!(OwnedCamera {
+    name, position, up, look_at, horizontal_fov, clip_plane_near, clip_plane_far, aspect
+});
+
+impl Encode for MaterialPropertyData {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match *self {
+            MaterialPropertyData::Float(ref data) => { 0u32.encode(out); data.encode(out); }
+            MaterialPropertyData::String(ref data) => { 1u32.encode(out); data.encode(out); }
+            MaterialPropertyData::Integer(ref data) => { 2u32.encode(out); data.encode(out); }
+            MaterialPropertyData::Buffer(ref data) => { 3u32.encode(out); data.encode(out); }
+        }
+    }
+
+    fn decode(input: &mut Reader) -> Result<MaterialPropertyData, CacheError> {
+        Ok(match try!(u32::decode(input)) {
+            0 => MaterialPropertyData::Float(try!(Encode::decode(input))),
+            1 => MaterialPropertyData::String(try!(Encode::decode(input))),
+            2 => MaterialPropertyData::Integer(try!(Encode::decode(input))),
+            3 => MaterialPropertyData::Buffer(try!(Encode::decode(input))),
+            _ => return Err(CacheError::InvalidFormat)
+        })
+    }
+}
+
+// Fieldless enums are stored as the index of the variant
+macro_rules! impl_encode_enum {
+    ($name:ident { $($variant:ident),* }) => (
+        impl Encode for $name {
+            fn encode(&self, out: &mut Vec<u8>) {
+                (*self as u32).encode(out);
+            }
+
+            fn decode(input: &mut Reader) -> Result<$name, CacheError> {
+                let tag = try!(u32::decode(input));
+                $(if tag == $name::$variant as u32 { return Ok($name::$variant); })*
+                Err(CacheError::InvalidFormat)
+            }
+        }
+    )
+}
+
+impl_encode_enum!(AnimBehaviour { Default, Constant, Linear, Repeat });
+impl_encode_enum!(LightSourceType { Undefined, Directional, Point, Spot });
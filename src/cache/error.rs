@@ -0,0 +1,70 @@
+//! Error type returned when loading a scene cache fails.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use import::ImportError;
+
+/// Describes why a cache couldn't be loaded or created.
+#[derive(Debug)]
+pub enum CacheError {
+    /// Reading or writing a file failed.
+    Io(io::Error),
+    /// The source file couldn't be imported.
+    Import(ImportError),
+    /// The data isn't a scene cache, or is truncated.
+    InvalidFormat,
+    /// The cache was written by a different version of the cache format.
+    UnsupportedVersion(u32),
+    /// The cache was created from a different source file or importer configuration.
+    KeyMismatch,
+    /// The checksum of the cached data doesn't match, i.e. the cache is corrupt.
+    ChecksumMismatch,
+    /// The cache can't be used in place, because the data isn't aligned to 8 bytes or the target
+    /// is big-endian. It can still be loaded with `load_cache`.
+    NotMappable
+}
+
+impl From<io::Error> for CacheError {
+    fn from(err: io::Error) -> CacheError {
+        CacheError::Io(err)
+    }
+}
+
+impl From<ImportError> for CacheError {
+    fn from(err: ImportError) -> CacheError {
+        CacheError::Import(err)
+    }
+}
+
+impl fmt::Display for CacheError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::CacheError::*;
+        match *self {
+            Io(ref err) => write!(f, "cache I/O failed: {}", err),
+            Import(ref err) => write!(f, "{}", err),
+            InvalidFormat => write!(f, "data isn't a valid scene cache"),
+            UnsupportedVersion(version) => {
+                write!(f, "scene cache version {} isn't supported", version)
+            }
+            KeyMismatch => write!(f, "scene cache is out of date"),
+            ChecksumMismatch => write!(f, "scene cache is corrupt"),
+            NotMappable => write!(f, "scene cache can't be used in place")
+        }
+    }
+}
+
+impl Error for CacheError {
+    fn description(&self) -> &str {
+        "failed to load scene cache"
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            CacheError::Io(ref err) => Some(err),
+            CacheError::Import(ref err) => Some(err),
+            _ => None
+        }
+    }
+}
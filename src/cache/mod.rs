@@ -0,0 +1,319 @@
+//! The `cache` module implements a binary cache for imported scenes.
+//!
+//! Importing a file parses the source format and runs post-processing every time. A cache stores
+//! the resulting `OwnedScene` in a binary format, so it can be loaded again without Assimp.
+//!
+//! Vertex data, faces, bone weights, animation keys and texture data are stored the same way as
+//! they're laid out in memory, at offsets aligned to 8 bytes. `load_cache` copies each of these
+//! arrays into an `OwnedScene` at once, and [`view_cache`](fn.view_cache.html) uses them in place,
+//! e.g. straight from a memory mapped cache file. `benches/cache.rs` compares both with importing
+//! a large model.
+//!
+//! Each cache records a [`CacheKey`](struct.CacheKey.html) computed from the contents of the
+//! source file and the configuration of the importer. Loading a cache with a different key fails,
+//! so a cache is never used after the source file or import settings change.
+//!
+//! Caches written by `read_file_cached` also record the other files Assimp looked for during the
+//! import, e.g. the material library of an OBJ file. Their contents are included in the key, so
+//! the cache is also invalidated when one of them changes, appears or is removed.
+//!
+//! # Examples
+//! ```
+//! use assimp::Importer;
+//! use assimp::cache;
+//!
+//! let mut importer = Importer::new();
+//! importer.triangulate(true);
+//!
+//! // Imports the file and writes the cache the first time, then loads the cache
+//! let path = std::env::temp_dir().join("box.obj.cache");
+//! let scene = cache::read_file_cached(&importer, "examples/box.obj", path.to_str().unwrap())
+//!     .unwrap();
+//! assert_eq!(scene.meshes[0].faces.len(), 12);
+//! ```
+//!
+//! # Format
+//! A cache starts with a 40 byte header, followed by the encoded scene:
+//!
+//! | Offset | Size | Contents |
+//! |--------|------|----------|
+//! | 0 | 8 | The magic bytes `AISCACHE` |
+//! | 8 | 4 | The format version, currently 2 |
+//! | 12 | 4 | Reserved, zero |
+//! | 16 | 8 | The cache key |
+//! | 24 | 8 | The length of the encoded scene |
+//! | 32 | 8 | The 64-bit FNV-1a hash of the encoded scene |
+//!
+//! The encoded scene starts with the list of dependencies, followed by the scene itself. All
+//! values are little-endian. Strings and arrays are stored as a `u64` length followed by their
+//! elements. For arrays of plain data, e.g. `Vector3D`, the length is followed by zero padding up
+//! to a multiple of 8 bytes from the start of the cache, and each element is followed by zero
+//! padding up to its size in memory.
+
+use std::cell::RefCell;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read, Write};
+
+use import::Importer;
+use import::structs::PropertyValue;
+use io::{FileSystem, MemoryFile};
+use owned::OwnedScene;
+
+pub use self::error::CacheError;
+pub use self::view::{AnimMeshView, AnimationView, BoneView, MeshAnimView, MeshView,
+                     NodeAnimView, SceneView, TextureView};
+
+use self::encode::{Encode, Reader};
+use self::view::View;
+
+mod encode;
+mod error;
+mod view;
+
+const MAGIC: &'static [u8; 8] = b"AISCACHE";
+const VERSION: u32 = 2;
+// A multiple of 8, so offsets in the encoded scene have the same alignment as in the cache
+const HEADER_LEN: usize = 40;
+
+/// Identifies the source data and importer configuration a cache was created from.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CacheKey(pub u64);
+
+impl CacheKey {
+    /// Computes the key for importing `source` with `importer`.
+    ///
+    /// The key covers the importer's post-processing steps and properties. Files referenced by
+    /// the source, e.g. the material library of an OBJ file, aren't included, use `from_files`
+    /// to include them.
+    pub fn new(source: &[u8], importer: &Importer) -> CacheKey {
+        let mut hash = fnv1a(FNV_OFFSET_BASIS, source);
+        hash = fnv1a(hash, &importer.post_process_flags().bits().to_le_bytes());
+        for (name, value) in importer.properties() {
+            hash = fnv1a(hash, name.as_bytes());
+            hash = match *value {
+                PropertyValue::Int(x) => fnv1a(fnv1a(hash, b"\0i"), &x.to_le_bytes()),
+                PropertyValue::Float(x) => fnv1a(fnv1a(hash, b"\0f"), &x.to_bits().to_le_bytes()),
+                PropertyValue::Matrix(ref m) => {
+                    let mut bytes = Vec::new();
+                    m.encode(&mut bytes);
+                    fnv1a(fnv1a(hash, b"\0m"), &bytes)
+                }
+                PropertyValue::String(ref x) => {
+                    let hash = fnv1a(fnv1a(hash, b"\0s"), &(x.len() as u64).to_le_bytes());
+                    fnv1a(hash, x.as_bytes())
+                }
+            };
+        }
+        CacheKey(hash)
+    }
+
+    /// Computes the key for importing the file at `path` with `importer`.
+    pub fn from_file(path: &str, importer: &Importer) -> io::Result<CacheKey> {
+        let source = try!(read_file(path));
+        Ok(CacheKey::new(&source, importer))
+    }
+
+    /// Computes the key for importing the file at `path` with `importer`, where the import also
+    /// looks for the files at `dependencies`.
+    ///
+    /// Whether each dependency exists is part of the key, so the key changes when a missing
+    /// dependency is created.
+    pub fn from_files<S: AsRef<str>>(path: &str, dependencies: &[S], importer: &Importer)
+                                     -> io::Result<CacheKey> {
+        let mut hash = try!(CacheKey::from_file(path, importer)).0;
+        for dependency in dependencies {
+            let dependency = dependency.as_ref();
+            hash = fnv1a(fnv1a(hash, &(dependency.len() as u64).to_le_bytes()),
+                         dependency.as_bytes());
+            hash = match read_file(dependency) {
+                Ok(data) => fnv1a(fnv1a(hash, &(data.len() as u64).to_le_bytes()), &data),
+                Err(_) => fnv1a(hash, b"\0missing")
+            };
+        }
+        Ok(CacheKey(hash))
+    }
+}
+
+/// Writes a cache of `scene`, recording `key` so the cache can be checked when it's loaded.
+pub fn save_cache<W: Write>(writer: &mut W, scene: &OwnedScene, key: CacheKey)
+                            -> io::Result<()> {
+    save_cache_with_dependencies::<W, String>(writer, scene, key, &[])
+}
+
+/// Writes a cache of `scene` like `save_cache`, also recording the paths of the files the import
+/// depended on. The key should be computed with `CacheKey::from_files` using the same paths.
+pub fn save_cache_with_dependencies<W, S>(writer: &mut W, scene: &OwnedScene, key: CacheKey,
+                                          dependencies: &[S]) -> io::Result<()>
+    where W: Write, S: AsRef<str>
+{
+    let mut data = Vec::with_capacity(HEADER_LEN);
+    data.extend_from_slice(MAGIC);
+    VERSION.encode(&mut data);
+    0u32.encode(&mut data);
+    key.0.encode(&mut data);
+    // Length and checksum are filled in once the scene has been encoded
+    data.extend_from_slice(&[0; 16]);
+    let dependencies: Vec<String> = dependencies.iter().map(|x| x.as_ref().to_owned()).collect();
+    dependencies.encode(&mut data);
+    scene.encode(&mut data);
+
+    let len = (data.len() - HEADER_LEN) as u64;
+    let checksum = fnv1a(FNV_OFFSET_BASIS, &data[HEADER_LEN..]);
+    data[24..32].copy_from_slice(&len.to_le_bytes());
+    data[32..40].copy_from_slice(&checksum.to_le_bytes());
+    writer.write_all(&data)
+}
+
+/// Loads a scene from cache data written by `save_cache`.
+///
+/// The data is checked for corruption, and must have been created with the same `key`.
+pub fn load_cache(data: &[u8], key: CacheKey) -> Result<OwnedScene, CacheError> {
+    let (cache_key, payload) = try!(read_header(data));
+    if cache_key != key.0 {
+        return Err(CacheError::KeyMismatch);
+    }
+    let mut reader = Reader::new(payload, HEADER_LEN);
+    try!(Vec::<String>::decode(&mut reader));
+    decode_scene(reader)
+}
+
+/// Loads a view of the scene in cache data written by `save_cache`, which borrows from `data`.
+///
+/// Vertex data, faces, bone weights, animation keys and texture data are used in place, without
+/// copying them, and names are borrowed too. Nodes, materials, lights and cameras are decoded
+/// into owned values. The data is checked for corruption, and must have been created with the
+/// same `key`.
+///
+/// `data` must start at an address aligned to 8 bytes, which memory maps are. The arrays can't be
+/// used in place otherwise, or on big-endian targets, so `CacheError::NotMappable` is returned
+/// and the cache must be loaded with `load_cache` instead.
+pub fn view_cache(data: &[u8], key: CacheKey) -> Result<SceneView, CacheError> {
+    let (cache_key, payload) = try!(read_header(data));
+    if cache_key != key.0 {
+        return Err(CacheError::KeyMismatch);
+    }
+    let mut reader = Reader::new(payload, HEADER_LEN);
+    try!(Vec::<String>::decode(&mut reader));
+    let scene = try!(SceneView::view(&mut reader));
+    if !reader.is_empty() {
+        return Err(CacheError::InvalidFormat);
+    }
+    Ok(scene)
+}
+
+/// Returns the paths of the files recorded as dependencies when the cache was written.
+pub fn cache_dependencies(data: &[u8]) -> Result<Vec<String>, CacheError> {
+    let (_, payload) = try!(read_header(data));
+    Vec::<String>::decode(&mut Reader::new(payload, HEADER_LEN))
+}
+
+/// Imports a file using a cache.
+///
+/// If `cache_file` contains a valid cache for the file and importer, the scene is loaded from it.
+/// Otherwise the file is imported, and the cache is created or replaced. All files Assimp looks
+/// for during the import are recorded as dependencies of the cache, so changing, creating or
+/// removing any of them causes the file to be imported again.
+pub fn read_file_cached(importer: &Importer, file: &str, cache_file: &str)
+                        -> Result<OwnedScene, CacheError> {
+    let mut data = Vec::new();
+    let cached = File::open(cache_file).and_then(|mut x| x.read_to_end(&mut data));
+    if cached.is_ok() {
+        if let Ok(scene) = load_file_cache(&data, importer, file) {
+            return Ok(scene);
+        }
+    }
+
+    let mut fs = DependencyFileSystem { paths: RefCell::new(Vec::new()) };
+    let scene = OwnedScene::from(&try!(importer.read_file_with_io(file, &mut fs)));
+    let dependencies: Vec<String> = fs.paths.into_inner()
+                                       .into_iter()
+                                       .filter(|x| x != file)
+                                       .collect();
+    let key = try!(CacheKey::from_files(file, &dependencies, importer));
+    let mut writer = BufWriter::new(try!(File::create(cache_file)));
+    try!(save_cache_with_dependencies(&mut writer, &scene, key, &dependencies));
+    try!(writer.flush());
+    Ok(scene)
+}
+
+/// Checks the header of a cache and the checksum of its contents, returning the key and the
+/// encoded scene.
+fn read_header(data: &[u8]) -> Result<(u64, &[u8]), CacheError> {
+    if data.len() < HEADER_LEN || &data[0..8] != MAGIC {
+        return Err(CacheError::InvalidFormat);
+    }
+    let mut header = Reader::new(&data[8..HEADER_LEN], 8);
+    let version = try!(u32::decode(&mut header));
+    if version != VERSION {
+        return Err(CacheError::UnsupportedVersion(version));
+    }
+    try!(u32::decode(&mut header));
+    let key = try!(u64::decode(&mut header));
+    let len = try!(u64::decode(&mut header));
+    let checksum = try!(u64::decode(&mut header));
+
+    let payload = &data[HEADER_LEN..];
+    if payload.len() as u64 != len {
+        return Err(CacheError::InvalidFormat);
+    }
+    if fnv1a(FNV_OFFSET_BASIS, payload) != checksum {
+        return Err(CacheError::ChecksumMismatch);
+    }
+    Ok((key, payload))
+}
+
+fn decode_scene(mut reader: Reader) -> Result<OwnedScene, CacheError> {
+    let scene = try!(OwnedScene::decode(&mut reader));
+    if !reader.is_empty() {
+        return Err(CacheError::InvalidFormat);
+    }
+    Ok(scene)
+}
+
+/// Loads the cache of `file`, checking it against the current contents of its dependencies.
+fn load_file_cache(data: &[u8], importer: &Importer, file: &str)
+                   -> Result<OwnedScene, CacheError> {
+    let (cache_key, payload) = try!(read_header(data));
+    let mut reader = Reader::new(payload, HEADER_LEN);
+    let dependencies = try!(Vec::<String>::decode(&mut reader));
+    if try!(CacheKey::from_files(file, &dependencies, importer)).0 != cache_key {
+        return Err(CacheError::KeyMismatch);
+    }
+    decode_scene(reader)
+}
+
+fn read_file(path: &str) -> io::Result<Vec<u8>> {
+    let mut data = Vec::new();
+    try!(try!(File::open(path)).read_to_end(&mut data));
+    Ok(data)
+}
+
+/// Reads files from disk, recording every path Assimp looks for.
+struct DependencyFileSystem {
+    paths: RefCell<Vec<String>>
+}
+
+impl FileSystem for DependencyFileSystem {
+    fn exists(&self, path: &str) -> bool {
+        let mut paths = self.paths.borrow_mut();
+        if !paths.iter().any(|x| x == path) {
+            paths.push(path.to_owned());
+        }
+        fs::metadata(path).map(|x| x.is_file()).unwrap_or(false)
+    }
+
+    fn open(&mut self, path: &str) -> Option<Box<::io::File>> {
+        read_file(path).ok().map(|data| Box::new(MemoryFile::new(data)) as Box<::io::File>)
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+fn fnv1a(mut hash: u64, data: &[u8]) -> u64 {
+    for &byte in data {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
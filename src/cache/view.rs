@@ -0,0 +1,173 @@
+//! Views of a cached scene which borrow their arrays from the cache data, see `view_cache`.
+//!
+//! Each view has the same fields as the corresponding owned type, in the same order, except
+//! that plain data arrays are slices of the cache data and names are `&str`. Nodes, materials,
+//! lights and cameras are small, so they are decoded into their owned types.
+
+use math::{Color4D, Matrix4x4, Vector3D};
+use owned::{AnimBehaviour, OwnedCamera, OwnedLight, OwnedMaterial, OwnedMeshKey, OwnedNode,
+            OwnedQuatKey, OwnedVectorKey, OwnedVertexWeight};
+
+use super::encode::{Encode, Pod, Reader};
+use super::error::CacheError;
+
+/// A view of an `OwnedScene` stored in a cache.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SceneView<'a> {
+    pub flags: u32,
+    pub root_node: OwnedNode,
+    pub meshes: Vec<MeshView<'a>>,
+    pub materials: Vec<OwnedMaterial>,
+    pub animations: Vec<AnimationView<'a>>,
+    pub textures: Vec<TextureView<'a>>,
+    pub lights: Vec<OwnedLight>,
+    pub cameras: Vec<OwnedCamera>
+}
+
+/// A view of an `OwnedMesh` stored in a cache.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeshView<'a> {
+    pub name: &'a str,
+    pub primitive_types: u32,
+    pub vertices: &'a [Vector3D],
+    pub normals: &'a [Vector3D],
+    pub tangents: &'a [Vector3D],
+    pub bitangents: &'a [Vector3D],
+    pub colors: Vec<&'a [Color4D]>,
+    pub texture_coords: Vec<&'a [Vector3D]>,
+    pub num_uv_components: &'a [u32],
+    pub faces: Vec<&'a [u32]>,
+    pub bones: Vec<BoneView<'a>>,
+    pub material_index: u32,
+    pub anim_meshes: Vec<AnimMeshView<'a>>
+}
+
+/// A view of an `OwnedBone` stored in a cache.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoneView<'a> {
+    pub name: &'a str,
+    pub weights: &'a [OwnedVertexWeight],
+    pub offset_matrix: Matrix4x4
+}
+
+/// A view of an `OwnedAnimMesh` stored in a cache.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnimMeshView<'a> {
+    pub vertices: &'a [Vector3D],
+    pub normals: &'a [Vector3D],
+    pub tangents: &'a [Vector3D],
+    pub bitangents: &'a [Vector3D],
+    pub colors: Vec<&'a [Color4D]>,
+    pub texture_coords: Vec<&'a [Vector3D]>
+}
+
+/// A view of an `OwnedAnimation` stored in a cache.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnimationView<'a> {
+    pub name: &'a str,
+    pub duration: f64,
+    pub ticks_per_second: f64,
+    pub channels: Vec<NodeAnimView<'a>>,
+    pub mesh_channels: Vec<MeshAnimView<'a>>
+}
+
+/// A view of an `OwnedNodeAnim` stored in a cache.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NodeAnimView<'a> {
+    pub node_name: &'a str,
+    pub position_keys: &'a [OwnedVectorKey],
+    pub rotation_keys: &'a [OwnedQuatKey],
+    pub scaling_keys: &'a [OwnedVectorKey],
+    pub pre_state: AnimBehaviour,
+    pub post_state: AnimBehaviour
+}
+
+/// A view of an `OwnedMeshAnim` stored in a cache.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeshAnimView<'a> {
+    pub name: &'a str,
+    pub keys: &'a [OwnedMeshKey]
+}
+
+/// A view of an `OwnedTexture` stored in a cache.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextureView<'a> {
+    pub width: u32,
+    pub height: u32,
+    pub format_hint: &'a str,
+    pub data: &'a [u8]
+}
+
+/// Decodes a value which may borrow from the cache data.
+pub trait View<'a>: Sized {
+    fn view(input: &mut Reader<'a>) -> Result<Self, CacheError>;
+}
+
+impl<'a> View<'a> for &'a str {
+    fn view(input: &mut Reader<'a>) -> Result<&'a str, CacheError> {
+        input.str()
+    }
+}
+
+impl<'a, T: Pod> View<'a> for &'a [T] {
+    fn view(input: &mut Reader<'a>) -> Result<&'a [T], CacheError> {
+        input.slice()
+    }
+}
+
+// Only used for arrays of views and owned structs, plain data arrays must be viewed as slices
+impl<'a, T: View<'a>> View<'a> for Vec<T> {
+    fn view(input: &mut Reader<'a>) -> Result<Vec<T>, CacheError> {
+        let len = try!(input.len());
+        let mut items = Vec::with_capacity(len);
+        for _ in 0..len {
+            items.push(try!(T::view(input)));
+        }
+        Ok(items)
+    }
+}
+
+// Types which are decoded into owned values
+macro_rules! impl_view_owned {
+    ($($ty:ty),*) => ($(
+        impl<'a> View<'a> for $ty {
+            fn view(input: &mut Reader<'a>) -> Result<$ty, CacheError> {
+                Encode::decode(input)
+            }
+        }
+    )*)
+}
+
+impl_view_owned!(u32, f64, Matrix4x4, AnimBehaviour, OwnedNode, OwnedMaterial, OwnedLight,
+                 OwnedCamera);
+
+// Fields must be listed in the same order as for the owned type in `impl_encode_struct!`
+macro_rules! impl_view_struct {
+    ($name:ident { $($field:ident),* }) => (
+        impl<'a> View<'a> for $name<'a> {
+            fn view(input: &mut Reader<'a>) -> Result<$name<'a>, CacheError> {
+                Ok($name { $($field: try!(View::view(input))),* })
+            }
+        }
+    )
+}
+
+impl_view_struct!(SceneView {
+    flags, root_node, meshes, materials, animations, textures, lights, cameras
+});
+impl_view_struct!(MeshView {
+    name, primitive_types, vertices, normals, tangents, bitangents, colors, texture_coords,
+    num_uv_components, faces, bones, material_index, anim_meshes
+});
+impl_view_struct!(BoneView { name, weights, offset_matrix });
+impl_view_struct!(AnimMeshView {
+    vertices, normals, tangents, bitangents, colors, texture_coords
+});
+impl_view_struct!(AnimationView {
+    name, duration, ticks_per_second, channels, mesh_channels
+});
+impl_view_struct!(NodeAnimView {
+    node_name, position_keys, rotation_keys, scaling_keys, pre_state, post_state
+});
+impl_view_struct!(MeshAnimView { name, keys });
+impl_view_struct!(TextureView { width, height, format_hint, data });
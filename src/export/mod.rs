@@ -1 +1,87 @@
-//! The `export` module implements functionality for exporting scenes (not yet implemented).
+//! The `export` module implements functionality for exporting scenes.
+//!
+//! Scenes are exported to memory with `Scene::export`, or `Scene::to_assbin` for Assimp's
+//! binary format.
+
+use std::error::Error;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::slice;
+
+use ffi::*;
+
+use log::{self, Severity};
+
+/// Describes why an export failed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportError {
+    format: String,
+    message: String
+}
+
+impl ExportError {
+    /// Returns the id of the format the scene was being exported to, e.g. "assbin".
+    pub fn format(&self) -> &str {
+        &self.format
+    }
+
+    /// Returns the reason the export failed, as reported by Assimp.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed to export scene to {}: {}", self.format, self.message)
+    }
+}
+
+impl Error for ExportError {
+    fn description(&self) -> &str {
+        "failed to export scene"
+    }
+}
+
+/// Exports a raw scene to memory in the format with the given id.
+///
+/// Assimp only reads the scene, so it may have been allocated outside of Assimp.
+pub(crate) unsafe fn export_to_bytes(scene: *const AiScene, format_id: &str)
+                              -> Result<Vec<u8>, ExportError> {
+    let error = |message: &str| ExportError {
+        format: format_id.to_owned(),
+        message: message.to_owned()
+    };
+    let cstr = match CString::new(format_id) {
+        Ok(cstr) => cstr,
+        Err(_) => return Err(error("Format id contains a NUL character"))
+    };
+    if !is_format_supported(&cstr) {
+        return Err(error("No exporter for the format is available"));
+    }
+
+    // Assimp keeps the reason for a failed export to itself, so use the last error it logged
+    let (blob, records) = log::capture(|| {
+        aiExportSceneToBlob(scene, cstr.as_ptr(), AiPostProcessSteps::empty())
+    });
+    if blob.is_null() {
+        let message = records.iter()
+                             .rev()
+                             .find(|x| x.severity == Severity::Error)
+                             .map(|x| &x.message[..])
+                             .unwrap_or("Unknown error");
+        return Err(error(message));
+    }
+
+    // Formats which write several files return a linked list of blobs, assbin only writes one
+    let data = slice::from_raw_parts((*blob).data as *const u8, (*blob).size as usize).to_vec();
+    aiReleaseExportBlob(blob);
+    Ok(data)
+}
+
+fn is_format_supported(format_id: &CStr) -> bool {
+    (0..unsafe { aiGetExportFormatCount() }).any(|i| unsafe {
+        let desc = aiGetExportFormatDescription(i);
+        !desc.is_null() && CStr::from_ptr((*desc).id) == format_id
+    })
+}
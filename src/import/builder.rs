@@ -0,0 +1,263 @@
+//! Builder for configuring an `Importer` with chained method calls.
+
+use super::Importer;
+use super::structs::*;
+
+// Define a builder method for a post-process step which is simply enabled or disabled
+macro_rules! flag_step {
+    ($(#[$attr:meta])* fn $name:ident) => (
+        $(#[$attr])*
+        pub fn $name(mut self, enable: bool) -> ImporterBuilder {
+            self.importer.$name(enable);
+            self
+        }
+    )
+}
+
+// Define a builder method for a post-process step configured by an argument struct
+macro_rules! args_step {
+    ($(#[$attr:meta])* fn $name:ident($args:ident)) => (
+        $(#[$attr])*
+        pub fn $name(mut self, args: $args) -> ImporterBuilder {
+            self.importer.$name(|x| *x = args.clone());
+            self
+        }
+    )
+}
+
+/// Builds an `Importer` with chained method calls.
+///
+/// # Examples
+/// ```
+/// use assimp::ImporterBuilder;
+/// use assimp::import::structs::{CalcTangentSpace, Preset};
+///
+/// let importer = ImporterBuilder::new()
+///     .preset(Preset::TargetRealtimeQuality)
+///     .calc_tangent_space(CalcTangentSpace {
+///         max_smoothing_angle: 30.0,
+///         ..CalcTangentSpace::enabled()
+///     })
+///     .flip_uvs(true)
+///     .build();
+/// let scene = importer.read_file("examples/box.obj");
+/// ```
+///
+/// Post-processing steps configured by an argument struct take the struct by value, use e.g.
+/// `CalcTangentSpace::enabled()` to enable a step with default arguments.
+pub struct ImporterBuilder {
+    importer: Importer
+}
+
+impl ImporterBuilder {
+    /// Create a builder for an `Importer` with no post-processing steps enabled.
+    pub fn new() -> ImporterBuilder {
+        ImporterBuilder { importer: Importer::new() }
+    }
+
+    /// Returns the configured `Importer`.
+    pub fn build(self) -> Importer {
+        self.importer
+    }
+
+    /// Enable all the post-processing steps of a preset, using default arguments for each step.
+    ///
+    /// Steps which are already enabled are reconfigured with default arguments, so the preset
+    /// should be applied before customising individual steps.
+    pub fn preset(self, preset: Preset) -> ImporterBuilder {
+        match preset {
+            Preset::ConvertToLeftHanded => {
+                self.make_left_handed(true)
+                    .flip_uvs(true)
+                    .flip_winding_order(true)
+            }
+            Preset::TargetRealtimeFast => {
+                self.calc_tangent_space(CalcTangentSpace::enabled())
+                    .generate_normals(GenerateNormals::enabled())
+                    .join_identical_vertices(true)
+                    .triangulate(true)
+                    .gen_uv_coords(true)
+                    .sort_by_primitive_type(SortByPrimitiveType::enabled())
+            }
+            Preset::TargetRealtimeQuality => {
+                self.calc_tangent_space(CalcTangentSpace::enabled())
+                    .generate_normals(GenerateNormals {
+                        smooth: true,
+                        ..GenerateNormals::enabled()
+                    })
+                    .join_identical_vertices(true)
+                    .improve_cache_locality(ImproveCacheLocality::enabled())
+                    .limit_bone_weights(LimitBoneWeights::enabled())
+                    .remove_redudant_materials(RemoveRedundantMaterials::enabled())
+                    .split_large_meshes(SplitLargeMeshes::enabled())
+                    .triangulate(true)
+                    .gen_uv_coords(true)
+                    .sort_by_primitive_type(SortByPrimitiveType::enabled())
+                    .find_degenerates(FindDegenerates::enabled())
+                    .find_invalid_data(FindInvalidData::enabled())
+            }
+            Preset::TargetRealtimeMaxQuality => {
+                self.preset(Preset::TargetRealtimeQuality)
+                    .find_instances(true)
+                    .validate_data_structure(true)
+                    .optimize_meshes(true)
+            }
+        }
+    }
+
+    /// Configure the importer directly, e.g. to set format-specific properties.
+    ///
+    /// ```
+    /// use assimp::ImporterBuilder;
+    ///
+    /// let importer = ImporterBuilder::new()
+    ///     .triangulate(true)
+    ///     .configure(|x| x.fbx_read_lights(false))
+    ///     .build();
+    /// ```
+    pub fn configure<F: FnOnce(&mut Importer)>(mut self, closure: F) -> ImporterBuilder {
+        closure(&mut self.importer);
+        self
+    }
+
+    flag_step! {
+        /// Enable or disable time measurements, see `Importer::measure_time`.
+        fn measure_time
+    }
+
+    flag_step! {
+        /// Enable or disable favouring speed over quality, see `Importer::favour_speed`.
+        fn favour_speed
+    }
+
+    args_step! {
+        /// Configure the step, see `Importer::calc_tangent_space`.
+        fn calc_tangent_space(CalcTangentSpace)
+    }
+
+    flag_step! {
+        /// Enable or disable the step, see `Importer::join_identical_vertices`.
+        fn join_identical_vertices
+    }
+
+    flag_step! {
+        /// Enable or disable the step, see `Importer::make_left_handed`.
+        fn make_left_handed
+    }
+
+    flag_step! {
+        /// Enable or disable the step, see `Importer::triangulate`.
+        fn triangulate
+    }
+
+    args_step! {
+        /// Configure the step, see `Importer::remove_component`.
+        fn remove_component(RemoveComponent)
+    }
+
+    args_step! {
+        /// Configure the step, see `Importer::generate_normals`.
+        fn generate_normals(GenerateNormals)
+    }
+
+    args_step! {
+        /// Configure the step, see `Importer::split_large_meshes`.
+        fn split_large_meshes(SplitLargeMeshes)
+    }
+
+    args_step! {
+        /// Configure the step, see `Importer::pre_transform_vertices`.
+        fn pre_transform_vertices(PreTransformVertices)
+    }
+
+    args_step! {
+        /// Configure the step, see `Importer::limit_bone_weights`.
+        fn limit_bone_weights(LimitBoneWeights)
+    }
+
+    flag_step! {
+        /// Enable or disable the step, see `Importer::validate_data_structure`.
+        fn validate_data_structure
+    }
+
+    args_step! {
+        /// Configure the step, see `Importer::improve_cache_locality`.
+        fn improve_cache_locality(ImproveCacheLocality)
+    }
+
+    args_step! {
+        /// Configure the step, see `Importer::remove_redudant_materials`.
+        fn remove_redudant_materials(RemoveRedundantMaterials)
+    }
+
+    flag_step! {
+        /// Enable or disable the step, see `Importer::fix_infacing_normals`.
+        fn fix_infacing_normals
+    }
+
+    args_step! {
+        /// Configure the step, see `Importer::sort_by_primitive_type`.
+        fn sort_by_primitive_type(SortByPrimitiveType)
+    }
+
+    args_step! {
+        /// Configure the step, see `Importer::find_degenerates`.
+        fn find_degenerates(FindDegenerates)
+    }
+
+    args_step! {
+        /// Configure the step, see `Importer::find_invalid_data`.
+        fn find_invalid_data(FindInvalidData)
+    }
+
+    flag_step! {
+        /// Enable or disable the step, see `Importer::gen_uv_coords`.
+        fn gen_uv_coords
+    }
+
+    args_step! {
+        /// Configure the step, see `Importer::transform_uv_coords`.
+        fn transform_uv_coords(TransformUVCoords)
+    }
+
+    flag_step! {
+        /// Enable or disable the step, see `Importer::find_instances`.
+        fn find_instances
+    }
+
+    flag_step! {
+        /// Enable or disable the step, see `Importer::optimize_meshes`.
+        fn optimize_meshes
+    }
+
+    args_step! {
+        /// Configure the step, see `Importer::optimize_graph`.
+        fn optimize_graph(OptimizeGraph)
+    }
+
+    flag_step! {
+        /// Enable or disable the step, see `Importer::flip_uvs`.
+        fn flip_uvs
+    }
+
+    flag_step! {
+        /// Enable or disable the step, see `Importer::flip_winding_order`.
+        fn flip_winding_order
+    }
+
+    args_step! {
+        /// Configure the step, see `Importer::split_by_bone_count`.
+        fn split_by_bone_count(SplitByBoneCount)
+    }
+
+    args_step! {
+        /// Configure the step, see `Importer::debone`.
+        fn debone(Debone)
+    }
+}
+
+impl Default for ImporterBuilder {
+    fn default() -> ImporterBuilder {
+        ImporterBuilder::new()
+    }
+}
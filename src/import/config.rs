@@ -0,0 +1,176 @@
+//! Import configuration which can be stored alongside assets.
+
+use super::Importer;
+use super::structs::*;
+
+/// Layer of a LightWave model to import, see `Importer::lwo_one_layer_only_str` and
+/// `Importer::lwo_one_layer_only_int`.
+///
+/// Serialized as either the name or the index of the layer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(untagged))]
+pub enum LwoLayer {
+    /// Import the layer with the given name.
+    Name(String),
+    /// Import the layer with the given zero-based index.
+    Index(i32)
+}
+
+/// A complete `Importer` configuration, covering all post-processing steps and properties.
+///
+/// Each post-processing step is configured by a field named after the `Importer` method which
+/// configures it. Importer properties are optional, and Assimp's default is used for any which
+/// are `None`. With the `serde` feature enabled the configuration can be serialized, e.g. to
+/// store import settings next to each asset. Fields missing when deserializing are set to their
+/// default values.
+///
+/// # Examples
+/// ```
+/// use assimp::import::{ImportConfig, Importer};
+///
+/// let mut config = ImportConfig::default();
+/// config.triangulate = true;
+/// config.generate_normals.enable = true;
+/// config.fbx_read_lights = Some(false);
+///
+/// let importer = Importer::from_config(&config);
+/// let scene = importer.read_file("examples/box.obj");
+/// ```
+// Values come before structs so the configuration can be serialized to TOML, where plain values
+// must be written before tables.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+pub struct ImportConfig {
+    pub join_identical_vertices: bool,
+    pub make_left_handed: bool,
+    pub triangulate: bool,
+    pub validate_data_structure: bool,
+    pub fix_infacing_normals: bool,
+    pub gen_uv_coords: bool,
+    pub find_instances: bool,
+    pub optimize_meshes: bool,
+    pub flip_uvs: bool,
+    pub flip_winding_order: bool,
+
+    pub measure_time: Option<bool>,
+    pub favour_speed: Option<bool>,
+    pub import_no_skeleton_meshes: Option<bool>,
+    pub import_mdl_colormap: Option<String>,
+    pub fbx_read_all_geometry_layers: Option<bool>,
+    pub fbx_read_all_materials: Option<bool>,
+    pub fbx_read_materials: Option<bool>,
+    pub fbx_read_cameras: Option<bool>,
+    pub fbx_read_lights: Option<bool>,
+    pub fbx_read_animations: Option<bool>,
+    pub fbx_strict_mode: Option<bool>,
+    pub fbx_preserve_pivots: Option<bool>,
+    pub fbx_optimize_empty_animation_curves: Option<bool>,
+    pub global_keyframe: Option<i32>,
+    pub md3_keyframe: Option<i32>,
+    pub md2_keyframe: Option<i32>,
+    pub mdl_keyframe: Option<i32>,
+    pub mdc_keyframe: Option<i32>,
+    pub smd_keyframe: Option<i32>,
+    pub unreal_keyframe: Option<i32>,
+    pub ac_separate_bf_cull: Option<bool>,
+    pub ac_eval_subdivision: Option<bool>,
+    pub unreal_handle_flags: Option<bool>,
+    pub ter_make_uvs: Option<bool>,
+    pub ase_reconstruct_normals: Option<bool>,
+    pub md3_handle_multipart: Option<bool>,
+    pub md3_skin_name: Option<String>,
+    pub md3_shader_src: Option<String>,
+    pub md5_no_anim_autoload: Option<bool>,
+    pub lws_anim_start: Option<i32>,
+    pub lws_anim_end: Option<i32>,
+    pub irr_anim_fps: Option<i32>,
+    pub ogre_material_file: Option<String>,
+    pub ogre_texture_type_from_filename: Option<bool>,
+    pub ifc_skip_space_representations: Option<bool>,
+    pub ifc_skip_curve_representations: Option<bool>,
+    pub ifc_custom_triangulation: Option<bool>,
+    pub collada_ignore_up_direction: Option<bool>,
+    pub lwo_one_layer_only: Option<LwoLayer>,
+
+    pub calc_tangent_space: CalcTangentSpace,
+    pub remove_component: RemoveComponent,
+    pub generate_normals: GenerateNormals,
+    pub split_large_meshes: SplitLargeMeshes,
+    pub pre_transform_vertices: PreTransformVertices,
+    pub limit_bone_weights: LimitBoneWeights,
+    pub improve_cache_locality: ImproveCacheLocality,
+    pub remove_redundant_materials: RemoveRedundantMaterials,
+    pub sort_by_primitive_type: SortByPrimitiveType,
+    pub find_degenerates: FindDegenerates,
+    pub find_invalid_data: FindInvalidData,
+    pub transform_uv_coords: TransformUVCoords,
+    pub optimize_graph: OptimizeGraph,
+    pub split_by_bone_count: SplitByBoneCount,
+    pub debone: Debone
+}
+
+// Apply each field with the `Importer` method of the same name
+macro_rules! apply_flags {
+    ($config:ident, $importer:ident, $($name:ident),*) => (
+        $($importer.$name($config.$name);)*
+    )
+}
+
+macro_rules! apply_args {
+    ($config:ident, $importer:ident, $($name:ident),*) => (
+        $($importer.$name(|x| *x = $config.$name.clone());)*
+    )
+}
+
+macro_rules! apply_properties {
+    ($config:ident, $importer:ident, $($name:ident),*) => (
+        $(if let Some(ref value) = $config.$name { $importer.$name(value.clone()); })*
+    )
+}
+
+macro_rules! apply_string_properties {
+    ($config:ident, $importer:ident, $($name:ident),*) => (
+        $(if let Some(ref value) = $config.$name { $importer.$name(value); })*
+    )
+}
+
+impl ImportConfig {
+    /// Configure `importer` with all the settings in this configuration.
+    pub fn apply(&self, importer: &mut Importer) {
+        apply_flags!(self, importer,
+                     join_identical_vertices, make_left_handed, triangulate,
+                     validate_data_structure, fix_infacing_normals, gen_uv_coords, find_instances,
+                     optimize_meshes, flip_uvs, flip_winding_order);
+
+        apply_args!(self, importer,
+                    calc_tangent_space, remove_component, generate_normals, split_large_meshes,
+                    pre_transform_vertices, limit_bone_weights, improve_cache_locality,
+                    sort_by_primitive_type, find_degenerates, find_invalid_data,
+                    transform_uv_coords, optimize_graph, split_by_bone_count, debone);
+        // Named after the misspelt `Importer` method
+        importer.remove_redudant_materials(|x| *x = self.remove_redundant_materials.clone());
+
+        apply_properties!(self, importer,
+                          measure_time, favour_speed, import_no_skeleton_meshes,
+                          fbx_read_all_geometry_layers, fbx_read_all_materials, fbx_read_materials,
+                          fbx_read_cameras, fbx_read_lights, fbx_read_animations, fbx_strict_mode,
+                          fbx_preserve_pivots, fbx_optimize_empty_animation_curves,
+                          global_keyframe, md3_keyframe, md2_keyframe, mdl_keyframe, mdc_keyframe,
+                          smd_keyframe, unreal_keyframe, ac_separate_bf_cull, ac_eval_subdivision,
+                          unreal_handle_flags, ter_make_uvs, ase_reconstruct_normals,
+                          md3_handle_multipart, md5_no_anim_autoload, lws_anim_start, lws_anim_end,
+                          irr_anim_fps, ogre_texture_type_from_filename,
+                          ifc_skip_space_representations, ifc_skip_curve_representations,
+                          ifc_custom_triangulation, collada_ignore_up_direction);
+
+        apply_string_properties!(self, importer,
+                                 import_mdl_colormap, md3_skin_name, md3_shader_src,
+                                 ogre_material_file);
+
+        match self.lwo_one_layer_only {
+            Some(LwoLayer::Name(ref name)) => importer.lwo_one_layer_only_str(name),
+            Some(LwoLayer::Index(index)) => importer.lwo_one_layer_only_int(index),
+            None => {}
+        }
+    }
+}
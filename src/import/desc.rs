@@ -0,0 +1,121 @@
+//! Descriptions of the file format importers built into Assimp.
+
+use std::ffi::CStr;
+
+use ffi::*;
+use libc::c_char;
+
+/// Capability flags reported by an importer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ImporterFlag {
+    /// The format has a textual encoding.
+    TextFlavour,
+    /// The format has a binary encoding.
+    BinaryFlavour,
+    /// The format has a compressed encoding.
+    CompressedFlavour,
+    /// The importer only supports a subset of the format's features.
+    LimitedSupport,
+    /// The importer is highly experimental and should be used with care.
+    Experimental
+}
+
+/// Describes a single file format importer.
+///
+/// Use `Importer::get_importer_desc_list` to retrieve the descriptions of all importers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImporterDesc {
+    /// Full name of the importer, e.g. "Wavefront Object Importer".
+    pub name: String,
+    /// Original author of the importer, may be empty.
+    pub author: String,
+    /// Current maintainer of the importer, may be empty.
+    pub maintainer: String,
+    /// Implementation comments, e.g. unimplemented features.
+    pub comments: String,
+    /// Capability flags of the importer.
+    pub flags: Vec<ImporterFlag>,
+    /// Minimum format version supported as `(major, minor)`, `(0, 0)` if not applicable.
+    pub min_version: (u32, u32),
+    /// Maximum format version supported as `(major, minor)`, `(0, 0)` if not applicable.
+    pub max_version: (u32, u32),
+    /// File extensions handled by the importer in lower-case with no leading wildcard or
+    /// period characters, e.g. "obj".
+    pub extensions: Vec<String>
+}
+
+impl ImporterDesc {
+    /// Returns true if the importer supports the textual flavour of the format.
+    pub fn supports_text(&self) -> bool {
+        self.flags.contains(&ImporterFlag::TextFlavour)
+    }
+
+    /// Returns true if the importer supports the binary flavour of the format.
+    pub fn supports_binary(&self) -> bool {
+        self.flags.contains(&ImporterFlag::BinaryFlavour)
+    }
+
+    /// Returns true if the importer supports the compressed flavour of the format.
+    pub fn supports_compressed(&self) -> bool {
+        self.flags.contains(&ImporterFlag::CompressedFlavour)
+    }
+
+    /// Returns true if the importer only has limited support for the format.
+    pub fn has_limited_support(&self) -> bool {
+        self.flags.contains(&ImporterFlag::LimitedSupport)
+    }
+
+    /// Returns true if the importer is experimental.
+    pub fn is_experimental(&self) -> bool {
+        self.flags.contains(&ImporterFlag::Experimental)
+    }
+
+    /// Returns true if the importer handles files with the given extension. The extension is
+    /// matched case-insensitively and may contain a leading wildcard or period, e.g. "*.obj".
+    pub fn handles_extension(&self, extension: &str) -> bool {
+        let extension = normalize_extension(extension);
+        self.extensions.iter().any(|x| *x == extension)
+    }
+
+    #[doc(hidden)]
+    pub fn from_raw(raw: &AiImporterDesc) -> ImporterDesc {
+        let flag_list = [
+            (AIIMPORTERFLAG_SUPPORT_TEXT_FLAVOUR, ImporterFlag::TextFlavour),
+            (AIIMPORTERFLAG_SUPPORT_BINARY_FLAVOUR, ImporterFlag::BinaryFlavour),
+            (AIIMPORTERFLAG_SUPPORT_COMPRESSED_FLAVOUR, ImporterFlag::CompressedFlavour),
+            (AIIMPORTERFLAG_LIMITED_SUPPORT, ImporterFlag::LimitedSupport),
+            (AIIMPORTERFLAG_EXPERIMENTAL, ImporterFlag::Experimental)
+        ];
+
+        ImporterDesc {
+            name: string_from_raw(raw.name),
+            author: string_from_raw(raw.author),
+            maintainer: string_from_raw(raw.maintainer),
+            comments: string_from_raw(raw.comments),
+            flags: flag_list.iter()
+                            .filter(|&&(raw_flag, _)| raw.flags.contains(raw_flag))
+                            .map(|&(_, flag)| flag)
+                            .collect(),
+            min_version: (raw.min_major, raw.min_minor),
+            max_version: (raw.max_major, raw.max_minor),
+            extensions: string_from_raw(raw.file_extensions)
+                            .split_whitespace()
+                            .map(normalize_extension)
+                            .collect()
+        }
+    }
+}
+
+/// Strip any leading wildcard or period characters and convert to lower-case.
+fn normalize_extension(extension: &str) -> String {
+    extension.trim_left_matches(|c: char| c == '*' || c == '.').to_lowercase()
+}
+
+fn string_from_raw(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        String::new()
+    } else {
+        let cstr = unsafe { CStr::from_ptr(ptr) };
+        String::from_utf8_lossy(cstr.to_bytes()).into_owned()
+    }
+}
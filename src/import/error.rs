@@ -0,0 +1,129 @@
+//! Error type returned when importing a scene fails.
+
+use std::error::Error;
+use std::fmt;
+
+/// Describes why an import failed.
+///
+/// Each variant carries the error message reported by Assimp, and the path of the file being
+/// imported where there is one (imports from memory have no path).
+#[derive(Clone, Debug, PartialEq)]
+pub enum ImportError {
+    /// The file doesn't exist or couldn't be opened.
+    FileNotFound {
+        path: String,
+        message: String
+    },
+    /// None of Assimp's importers support the format of the file.
+    UnsupportedFormat {
+        path: Option<String>,
+        message: String
+    },
+    /// The importer failed to read the file, e.g. because it is malformed.
+    ParseError {
+        path: Option<String>,
+        message: String
+    },
+    /// The imported data failed the `validate_data_structure` post-process step.
+    ValidationFailed {
+        path: Option<String>,
+        message: String
+    },
+    /// One of the enabled post-processing steps failed.
+    PostProcessFailed {
+        path: Option<String>,
+        message: String
+    },
+    /// The path, or format hint, contains a NUL character and can't be passed to Assimp.
+    InvalidPath {
+        path: String
+    },
+    /// The buffer passed to `read_bytes` is larger than Assimp can import from memory.
+    DataTooLarge {
+        len: usize
+    }
+}
+
+impl ImportError {
+    /// Returns the error message.
+    pub fn message(&self) -> &str {
+        use self::ImportError::*;
+        match *self {
+            FileNotFound { ref message, .. } |
+            UnsupportedFormat { ref message, .. } |
+            ParseError { ref message, .. } |
+            ValidationFailed { ref message, .. } |
+            PostProcessFailed { ref message, .. } => message,
+            InvalidPath { .. } => "Path contains a NUL character",
+            DataTooLarge { .. } => "Data is too large to import from memory"
+        }
+    }
+
+    /// Returns the path of the file being imported, if there is one.
+    pub fn path(&self) -> Option<&str> {
+        use self::ImportError::*;
+        match *self {
+            FileNotFound { ref path, .. } |
+            InvalidPath { ref path } => Some(path),
+            UnsupportedFormat { ref path, .. } |
+            ParseError { ref path, .. } |
+            ValidationFailed { ref path, .. } |
+            PostProcessFailed { ref path, .. } => path.as_ref().map(|x| &x[..]),
+            DataTooLarge { .. } => None
+        }
+    }
+
+    /// Classify an error message reported by Assimp for a failed import.
+    #[doc(hidden)]
+    pub fn from_message(message: String, path: Option<&str>) -> ImportError {
+        if message.starts_with("Unable to open file") {
+            if let Some(path) = path {
+                return ImportError::FileNotFound { path: path.to_owned(), message: message };
+            }
+        }
+
+        let path = path.map(|x| x.to_owned());
+        if message.starts_with("No suitable reader found") {
+            ImportError::UnsupportedFormat { path: path, message: message }
+        } else if message.starts_with("Validation failed") {
+            ImportError::ValidationFailed { path: path, message: message }
+        } else {
+            ImportError::ParseError { path: path, message: message }
+        }
+    }
+
+    /// Classify an error message logged by Assimp for a failed post-processing step.
+    #[doc(hidden)]
+    pub fn from_postprocess_message(message: String, path: Option<&str>) -> ImportError {
+        let path = path.map(|x| x.to_owned());
+        if message.starts_with("Validation failed") {
+            ImportError::ValidationFailed { path: path, message: message }
+        } else {
+            ImportError::PostProcessFailed { path: path, message: message }
+        }
+    }
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.path() {
+            Some(path) => write!(f, "failed to import \"{}\": {}", path, self.message()),
+            None => write!(f, "failed to import: {}", self.message())
+        }
+    }
+}
+
+impl Error for ImportError {
+    fn description(&self) -> &str {
+        use self::ImportError::*;
+        match *self {
+            FileNotFound { .. } => "file not found",
+            UnsupportedFormat { .. } => "unsupported file format",
+            ParseError { .. } => "failed to parse file",
+            ValidationFailed { .. } => "scene validation failed",
+            PostProcessFailed { .. } => "post-processing failed",
+            InvalidPath { .. } => "path contains a NUL character",
+            DataTooLarge { .. } => "data too large"
+        }
+    }
+}
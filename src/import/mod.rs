@@ -13,91 +13,187 @@
 //! }
 //! ```
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::ffi::{CStr, CString};
+use std::fmt;
 use std::mem;
 use std::ptr;
-use std::str;
+use std::sync::{Mutex, MutexGuard};
 
 use ffi::*;
 use ffi::config::*;
+use libc::{c_char, c_uint, size_t};
 
+use io::{FileIO, FileSystem};
+use log::{self, LogRecord, Severity};
 use math::matrix4::*;
 use scene::*;
 
+pub use self::builder::ImporterBuilder;
+pub use self::config::{ImportConfig, LwoLayer};
+pub use self::error::ImportError;
+
+mod builder;
+mod config;
+pub mod desc;
+mod error;
 pub mod structs;
+use self::desc::*;
 use self::structs::*;
 
+// Not exposed by assimp-sys yet, available since Assimp 3.2.
+#[link(name = "assimp")]
+extern {
+    fn aiGetImportFormatCount() -> size_t;
+    fn aiGetImportFormatDescription(index: size_t) -> *const AiImporterDesc;
+}
+
 /// The `Importer` type.
 ///
+/// An `Importer` can be moved to another thread but can't be shared between threads, so create
+/// an importer for each thread which imports scenes. Assimp reports import errors through state
+/// shared by the whole process, so imports started on several threads run one at a time.
+///
 /// See [module-level documentation](index.html) for examples.
 pub struct Importer {
     property_store: *mut AiPropertyStore,
-    flags: AiPostProcessSteps
+    flags: AiPostProcessSteps,
+    // Assimp's property store is write-only, so keep a copy of each property
+    properties: BTreeMap<String, PropertyValue>
 }
 
+// Safety: the property store is owned by the importer and isn't shared with anything else, so the
+// importer can be moved to another thread. It isn't Sync as Assimp gives no guarantees about
+// reading a property store from several imports at once.
+unsafe impl Send for Importer {}
+
 impl Importer {
     /// Create a new Importer.
     pub fn new() -> Importer {
         Importer {
             property_store: unsafe { aiCreatePropertyStore() },
-            flags: AiPostProcessSteps::empty()
+            flags: AiPostProcessSteps::empty(),
+            properties: BTreeMap::new()
         }
     }
 
+    /// Create a new Importer configured with the settings in `config`.
+    pub fn from_config(config: &ImportConfig) -> Importer {
+        let mut importer = Importer::new();
+        config.apply(&mut importer);
+        importer
+    }
+
     /// Load a scene from the specified file.
     ///
     /// If the call succeeds, return value is `Ok`, containing the loaded `Scene` structure.
-    /// If the call fails, return value is `Err`, containing the error string returned from
-    /// the Assimp library.
-    pub fn read_file<'a>(&self, file: &str) -> Result<Scene<'a>, &str> {
-        let cstr = CString::new(file).unwrap().as_ptr();
-        let raw_scene = unsafe {
-            aiImportFileExWithProperties(cstr, self.flags, ptr::null_mut(), self.property_store)
+    /// If the call fails, return value is `Err`, containing an `ImportError` describing the
+    /// failure.
+    pub fn read_file(&self, file: &str) -> Result<Scene, ImportError> {
+        let cstr = try!(path_to_cstring(file));
+        self.import_scene(Some(file), || unsafe {
+            aiImportFileExWithProperties(cstr.as_ptr(),
+                                         AiPostProcessSteps::empty(),
+                                         ptr::null_mut(),
+                                         self.property_store)
+        })
+    }
+
+    /// Load a scene from the specified file, capturing all messages logged during the import.
+    ///
+    /// Assimp's log streams and last error string are shared by the whole process, so messages
+    /// from imports running concurrently on other threads are interleaved. This method instead
+    /// returns only the messages logged by this import, along with the result. If the import
+    /// fails, the error is built from the last error message logged by this import. Debug
+    /// messages are only logged if verbose logging is enabled with
+    /// `LogStream::set_verbose_logging`.
+    ///
+    /// Messages are still written to any attached log streams.
+    pub fn read_file_with_log(&self, file: &str) -> (Result<Scene, ImportError>, Vec<LogRecord>) {
+        let cstr = match path_to_cstring(file) {
+            Ok(cstr) => cstr,
+            Err(err) => return (Err(err), Vec::new())
         };
-        if !raw_scene.is_null() {
-            Ok(Scene::from_raw(raw_scene))
-        } else {
-            let error_str = unsafe { aiGetErrorString() };
-            if error_str.is_null() {
-                Err("Unknown error")
+        let _lock = lock_imports();
+        let (result, records) = log::capture(|| {
+            let raw_scene = unsafe {
+                aiImportFileExWithProperties(cstr.as_ptr(),
+                                             AiPostProcessSteps::empty(),
+                                             ptr::null_mut(),
+                                             self.property_store)
+            };
+            if raw_scene.is_null() {
+                None
             } else {
-                unsafe {
-                    let cstr = CStr::from_ptr(error_str);
-                    match str::from_utf8(cstr.to_bytes()) {
-                        Ok(s) => Err(s),
-                        Err(_) => Err("Unknown error")
-                    }
-                }
+                Some(self.run_postprocessing(unsafe { Scene::from_raw(raw_scene) }, Some(file)))
             }
-        }
+        });
+
+        let result = result.unwrap_or_else(|| {
+            let message = last_logged_error(&records).unwrap_or_else(last_error_string);
+            Err(ImportError::from_message(message, Some(file)))
+        });
+        (result, records)
+    }
+
+    /// Load a scene from the specified file, reading all files through a custom file system.
+    ///
+    /// Both `file` and any files it references (e.g. OBJ materials, glTF buffers) are read
+    /// through `fs` instead of from disk. See the [`io` module](../io/index.html) for details.
+    /// Imports run one at a time, so `fs` must not import scenes itself.
+    ///
+    /// If the call succeeds, return value is `Ok`, containing the loaded `Scene` structure.
+    /// If the call fails, return value is `Err`, containing an `ImportError` describing the
+    /// failure.
+    pub fn read_file_with_io(&self, file: &str, fs: &mut FileSystem)
+                             -> Result<Scene, ImportError> {
+        let cstr = try!(path_to_cstring(file));
+        let mut io = FileIO::new(fs);
+        self.import_scene(Some(file), || unsafe {
+            aiImportFileExWithProperties(cstr.as_ptr(),
+                                         AiPostProcessSteps::empty(),
+                                         io.as_raw(),
+                                         self.property_store)
+        })
     }
 
     /// Load a scene from a string.
     ///
+    /// This is equivalent to calling [`read_bytes`](#method.read_bytes) with no format hint.
+    ///
     /// If the call succeeds, return value is `Ok`, containing the loaded `Scene` structure.
-    /// If the call fails, return value is `Err`, containing the error string returned from
-    /// the Assimp library.
-    pub fn read_string<'a>(&self, data: &str) -> Result<Scene<'a>, &str> {
-        let cstr = CString::new(data).unwrap().as_ptr();
-        let raw_scene = unsafe {
-            aiImportFromMemoryWithProperties(cstr, data.len() as u32, self.flags, ptr::null_mut(), self.property_store)
-        };
-        if !raw_scene.is_null() {
-            Ok(Scene::from_raw(raw_scene))
-        } else {
-            let error_str = unsafe { aiGetErrorString() };
-            if error_str.is_null() {
-                Err("Unknown error")
-            } else {
-                unsafe {
-                    let cstr = CStr::from_ptr(error_str);
-                    match str::from_utf8(cstr.to_bytes()) {
-                        Ok(s) => Err(s),
-                        Err(_) => Err("Unknown error")
-                    }
-                }
-            }
+    /// If the call fails, return value is `Err`, containing an `ImportError` describing the
+    /// failure.
+    pub fn read_string(&self, data: &str) -> Result<Scene, ImportError> {
+        self.read_bytes(data.as_bytes(), "")
+    }
+
+    /// Load a scene from a memory buffer.
+    ///
+    /// The buffer may contain binary data, e.g. the contents of a binary FBX, GLB or PLY file.
+    /// `hint` is the file extension of the data, e.g. "fbx", and is used by Assimp to select an
+    /// importer when the format can't be detected from the contents alone. Pass an empty string
+    /// if the format is unknown.
+    ///
+    /// Formats which reference external files (e.g. OBJ with MTL materials) can't resolve those
+    /// files when loaded from memory. Assimp can't import buffers larger than 4 GiB from memory.
+    ///
+    /// If the call succeeds, return value is `Ok`, containing the loaded `Scene` structure.
+    /// If the call fails, return value is `Err`, containing an `ImportError` describing the
+    /// failure.
+    pub fn read_bytes(&self, data: &[u8], hint: &str) -> Result<Scene, ImportError> {
+        if data.len() > c_uint::max_value() as usize {
+            return Err(ImportError::DataTooLarge { len: data.len() });
         }
+        let hint = hint.trim_left_matches(|c: char| c == '*' || c == '.');
+        let cstr = try!(path_to_cstring(hint));
+        self.import_scene(None, || unsafe {
+            aiImportFromMemoryWithProperties(data.as_ptr() as *const c_char,
+                                             data.len() as c_uint,
+                                             AiPostProcessSteps::empty(),
+                                             cstr.as_ptr(),
+                                             self.property_store)
+        })
     }
 
     /// Apply post-processing to an already-imported scene.
@@ -106,14 +202,36 @@ impl Importer {
     /// use case for this is to inspect the scene returned by `read_file` before choosing which
     /// additional post-process steps to apply.
     ///
-    /// Due to how the Assimp C API works, this isn't as useful as it should be. Currently it isn't
-    /// possible to configure properties of post-processing steps after the initial import.
+    /// Due to how the Assimp C API works, this isn't as useful as it should be. Post-processing
+    /// steps use the properties of the importer which imported the scene, not those of this
+    /// importer. Use [`postprocess_copy`](#method.postprocess_copy) to apply steps with different
+    /// properties. Scenes created by `Scene::clone_deep` aren't associated with an importer, so
+    /// they are always processed the same way as by `postprocess_copy`.
     ///
     /// # Return value
     /// The new scene, with new post-processing steps applied. Note that it is possible for this
-    /// method to fail, in which case the return value is `Err`.
-    pub fn apply_postprocessing<'a>(&'a self, scene: Scene<'a>) -> Result<Scene, &str> {
-        let raw_scene = unsafe { aiApplyPostProcessing(scene.to_raw(), self.flags) };
+    /// method to fail, in which case the return value is `Err` and the scene is released.
+    pub fn apply_postprocessing(&self, scene: Scene) -> Result<Scene, ImportError> {
+        if scene.is_copy() {
+            // Assimp can't find an importer for the copy, so it would fail without releasing it
+            return self.postprocess_copy(&scene);
+        }
+
+        self.run_postprocessing(scene, None)
+    }
+
+    /// Runs the enabled post-processing steps on a scene imported by Assimp.
+    ///
+    /// Imports run without any steps, and the steps are applied afterwards, so failures of the
+    /// import and of the steps can be told apart.
+    fn run_postprocessing(&self, scene: Scene, path: Option<&str>) -> Result<Scene, ImportError> {
+        if self.flags.is_empty() {
+            return Ok(scene);
+        }
+
+        let (raw_scene, records) = log::capture(|| unsafe {
+            aiApplyPostProcessing(scene.to_raw(), self.flags)
+        });
         if !raw_scene.is_null() {
             // Return original scene, Assimp applies post-processing in-place so returning
             // a new scene object would cause the scene to get double-dropped.
@@ -122,8 +240,80 @@ impl Importer {
             // Assimp frees the scene on failure, dropping would cause the memory to be
             // freed twice so use mem::forget to prevent that happening.
             mem::forget(scene);
-            Err("apply_postprocessing failed, see output log for errors.")
+            // Assimp only reports why a step failed through the log
+            let message = last_logged_error(&records);
+            let message = message.unwrap_or_else(|| "Unknown error".to_owned());
+            Err(ImportError::from_postprocess_message(message, path))
+        }
+    }
+
+    /// Helper function to run an import without post-processing steps, then apply the enabled
+    /// steps. Fetches the error string if the import failed.
+    fn import_scene<F>(&self, path: Option<&str>, import: F) -> Result<Scene, ImportError>
+        where F: FnOnce() -> *const AiScene
+    {
+        let _lock = lock_imports();
+        let raw_scene = import();
+        if raw_scene.is_null() {
+            return Err(ImportError::from_message(last_error_string(), path));
         }
+        self.run_postprocessing(unsafe { Scene::from_raw(raw_scene) }, path)
+    }
+
+    /// Applies post-processing to a copy of an already imported scene, using the properties and
+    /// post-processing steps configured on this importer.
+    ///
+    /// Unlike [`apply_postprocessing`](#method.apply_postprocessing), properties of this importer
+    /// (e.g. `split_large_meshes` limits or `generate_normals` smoothing angles) are used by the
+    /// post-processing steps. This allows inspecting a scene before choosing how to process it:
+    ///
+    /// ```
+    /// use assimp::Importer;
+    ///
+    /// let scene = Importer::new().read_file("examples/box.obj").unwrap();
+    ///
+    /// let mut importer = Importer::new();
+    /// importer.split_large_meshes(|x| {
+    ///     x.enable = true;
+    ///     x.vertex_limit = if scene.num_meshes() > 1 { 1000 } else { 100 };
+    /// });
+    /// let processed = importer.postprocess_copy(&scene).unwrap();
+    /// ```
+    ///
+    /// The copy is made by exporting the scene to Assimp's binary format and importing it again,
+    /// so `scene` is left unchanged. This requires Assimp to be built with the assbin exporter and
+    /// importer, which are enabled by default.
+    ///
+    /// # Return value
+    /// The new scene, with post-processing steps applied. If the copy or any of the steps fail,
+    /// the return value is `Err`.
+    pub fn postprocess_copy(&self, scene: &Scene) -> Result<Scene, ImportError> {
+        match scene.to_assbin() {
+            Ok(data) => self.read_assbin(&data),
+            Err(err) => Err(ImportError::PostProcessFailed {
+                path: None,
+                message: format!("failed to copy scene: {}", err)
+            })
+        }
+    }
+
+    /// Load a scene from data in Assimp's binary format, e.g. as written by `Scene::to_assbin`.
+    ///
+    /// The configured post-processing steps are applied to the loaded scene.
+    ///
+    /// # Examples
+    /// ```
+    /// use assimp::Importer;
+    ///
+    /// let importer = Importer::new();
+    /// let scene = importer.read_file("examples/box.obj").unwrap();
+    /// let data = scene.to_assbin().unwrap();
+    ///
+    /// let copy = importer.read_assbin(&data).unwrap();
+    /// assert_eq!(copy.num_meshes(), scene.num_meshes());
+    /// ```
+    pub fn read_assbin(&self, data: &[u8]) -> Result<Scene, ImportError> {
+        self.read_bytes(data, "assbin")
     }
 
     /// Enables time measurements.
@@ -142,6 +332,34 @@ impl Importer {
         self.set_bool_property(FAVOUR_SPEED, enable);
     }
 
+    /// Returns the enabled post-processing steps.
+    pub fn post_process_steps(&self) -> BTreeSet<PostProcessStep> {
+        POST_PROCESS_STEPS.iter()
+            .filter(|&&(_, flag)| self.flags.contains(flag))
+            .map(|&(step, _)| step)
+            .collect()
+    }
+
+    /// Returns the values of all properties set on this importer, keyed by the name of the
+    /// property (e.g. `"PP_CT_MAX_SMOOTHING_ANGLE"`).
+    ///
+    /// Properties which haven't been set aren't included, and Assimp uses its default value for
+    /// them.
+    pub fn properties(&self) -> &BTreeMap<String, PropertyValue> {
+        &self.properties
+    }
+
+    /// Returns the value of the property `name`, or `None` if it hasn't been set.
+    pub fn property(&self, name: &str) -> Option<&PropertyValue> {
+        self.properties.get(name)
+    }
+
+    /// Returns the raw flags of the enabled post-processing steps.
+    #[doc(hidden)]
+    pub fn post_process_flags(&self) -> AiPostProcessSteps {
+        self.flags
+    }
+
     /// Helper method to set or clear the appropriate import flag
     fn set_import_flag(&mut self, flag: AiPostProcessSteps, value: bool) {
         if value {
@@ -158,27 +376,31 @@ impl Importer {
 
     /// Helper method to set an integer import property.
     fn set_int_property(&mut self, name: &str, value: i32) {
-        let cstr = CString::new(name).unwrap().as_ptr();
-        unsafe { aiSetImportPropertyInteger(self.property_store, cstr, value); }
+        let cstr = CString::new(name).unwrap();
+        unsafe { aiSetImportPropertyInteger(self.property_store, cstr.as_ptr(), value); }
+        self.properties.insert(name.to_owned(), PropertyValue::Int(value));
     }
 
     /// Helper method to set a floating point import property.
     fn set_float_property(&mut self, name: &str, value: f32) {
-        let cstr = CString::new(name).unwrap().as_ptr();
-        unsafe { aiSetImportPropertyFloat(self.property_store, cstr, value); }
+        let cstr = CString::new(name).unwrap();
+        unsafe { aiSetImportPropertyFloat(self.property_store, cstr.as_ptr(), value); }
+        self.properties.insert(name.to_owned(), PropertyValue::Float(value));
     }
 
     /// Helper method to set a 4x4 matrix import property.
     fn set_matrix_property(&mut self, name: &str, value: Matrix4x4) {
-        let cstr = CString::new(name).unwrap().as_ptr();
-        unsafe { aiSetImportPropertyMatrix(self.property_store, cstr, &*value); }
+        let cstr = CString::new(name).unwrap();
+        unsafe { aiSetImportPropertyMatrix(self.property_store, cstr.as_ptr(), &*value); }
+        self.properties.insert(name.to_owned(), PropertyValue::Matrix(value));
     }
 
     /// Helper method to set a string import property.
     fn set_string_property(&mut self, name: &str, value: &str) {
-        let cstr = CString::new(name).unwrap().as_ptr();
+        let cstr = CString::new(name).unwrap();
         let aistr: AiString = From::from(value);
-        unsafe { aiSetImportPropertyString(self.property_store, cstr, &aistr) }
+        unsafe { aiSetImportPropertyString(self.property_store, cstr.as_ptr(), &aistr) }
+        self.properties.insert(name.to_owned(), PropertyValue::String(value.to_owned()));
     }
 
     /// Calculates the tangents and bitangents for the imported meshes.
@@ -297,10 +519,13 @@ impl Importer {
         closure(&mut args);
 
         if args.enable {
+            // The steps are mutually exclusive, so make sure only one of them is enabled
             if args.smooth {
+                self.flags.remove(AIPROCESS_GEN_NORMALS);
                 self.flags.insert(AIPROCESS_GEN_SMOOTH_NORMALS);
                 self.set_float_property(PP_GSN_MAX_SMOOTHING_ANGLE, args.max_smoothing_angle);
             } else {
+                self.flags.remove(AIPROCESS_GEN_SMOOTH_NORMALS);
                 self.flags.insert(AIPROCESS_GEN_NORMALS);
             }
         } else {
@@ -1077,6 +1302,111 @@ impl Importer {
         let extensions = ext_list.as_ref().split(';');
         extensions.map(|x| x.trim_left_matches("*.").to_owned()).collect()
     }
+
+    /// Get descriptions of all file format importers compiled into Assimp.
+    ///
+    /// Unlike [`get_extension_list`](#method.get_extension_list), this contains the name,
+    /// capabilities and supported format versions of each importer along with the extensions it
+    /// handles.
+    pub fn get_importer_desc_list() -> Vec<ImporterDesc> {
+        let count = unsafe { aiGetImportFormatCount() };
+        (0..count).filter_map(|i| {
+            let raw = unsafe { aiGetImportFormatDescription(i) };
+            if !raw.is_null() {
+                Some(ImporterDesc::from_raw(unsafe { &*raw }))
+            } else {
+                None
+            }
+        }).collect()
+    }
+
+    /// Returns true if Assimp has an importer for files with the given extension.
+    ///
+    /// The extension is matched case-insensitively and may contain a leading wildcard or period,
+    /// e.g. "obj", ".obj" and "*.obj" are all accepted. As with `get_extension_list`, a supported
+    /// extension does not guarantee that Assimp is able to load all files with this extension.
+    pub fn can_read(extension: &str) -> bool {
+        let extension = extension.trim_left_matches(|c: char| c == '*' || c == '.');
+        match CString::new(format!("*.{}", extension)) {
+            Ok(cstr) => unsafe { aiIsExtensionSupported(cstr.as_ptr()) == AI_TRUE },
+            Err(_) => false
+        }
+    }
+}
+
+/// Each post-processing step and the Assimp flag which enables it.
+const POST_PROCESS_STEPS: [(PostProcessStep, AiPostProcessSteps); 26] = [
+    (PostProcessStep::CalcTangentSpace, AIPROCESS_CALC_TANGENT_SPACE),
+    (PostProcessStep::JoinIdenticalVertices, AIPROCESS_JOIN_IDENTICAL_VERTICES),
+    (PostProcessStep::MakeLeftHanded, AIPROCESS_MAKE_LEFT_HANDED),
+    (PostProcessStep::Triangulate, AIPROCESS_TRIANGULATE),
+    (PostProcessStep::RemoveComponent, AIPROCESS_REMOVE_COMPONENT),
+    (PostProcessStep::GenerateNormals, AIPROCESS_GEN_NORMALS),
+    (PostProcessStep::GenerateSmoothNormals, AIPROCESS_GEN_SMOOTH_NORMALS),
+    (PostProcessStep::SplitLargeMeshes, AIPROCESS_SPLIT_LARGE_MESHES),
+    (PostProcessStep::PreTransformVertices, AIPROCESS_PRE_TRANSFORM_VERTICES),
+    (PostProcessStep::LimitBoneWeights, AIPROCESS_LIMIT_BONE_WEIGHTS),
+    (PostProcessStep::ValidateDataStructure, AIPROCESS_VALIDATE_DATA_STRUCTURE),
+    (PostProcessStep::ImproveCacheLocality, AIPROCESS_IMPROVE_CACHE_LOCALITY),
+    (PostProcessStep::RemoveRedundantMaterials, AIPROCESS_REMOVE_REDUNDANT_MATERIALS),
+    (PostProcessStep::FixInfacingNormals, AIPROCESS_FIX_INFACING_NORMALS),
+    (PostProcessStep::SortByPrimitiveType, AIPROCESS_SORT_BY_PTYPE),
+    (PostProcessStep::FindDegenerates, AIPROCESS_FIND_DEGENERATES),
+    (PostProcessStep::FindInvalidData, AIPROCESS_FIND_INVALID_DATA),
+    (PostProcessStep::GenUVCoords, AIPROCESS_GEN_UV_COORDS),
+    (PostProcessStep::TransformUVCoords, AIPROCESS_TRANSFORM_UV_COORDS),
+    (PostProcessStep::FindInstances, AIPROCESS_FIND_INSTANCES),
+    (PostProcessStep::OptimizeMeshes, AIPROCESS_OPTIMIZE_MESHES),
+    (PostProcessStep::OptimizeGraph, AIPROCESS_OPTIMIZE_GRAPH),
+    (PostProcessStep::FlipUVs, AIPROCESS_FLIP_UVS),
+    (PostProcessStep::FlipWindingOrder, AIPROCESS_FLIP_WINDING_ORDER),
+    (PostProcessStep::SplitByBoneCount, AIPROCESS_SPLIT_BY_BONE_COUNT),
+    (PostProcessStep::Debone, AIPROCESS_DEBONE)
+];
+
+/// Helper function to convert a path to a C string, failing if it contains a NUL character.
+fn path_to_cstring(path: &str) -> Result<CString, ImportError> {
+    CString::new(path).map_err(|_| ImportError::InvalidPath { path: path.to_owned() })
+}
+
+// Assimp stores the error of a failed import in a single unsynchronized string shared by the whole
+// process, so imports running at the same time would race on it. Imports are run one at a time,
+// and the error is read before the next import starts.
+static IMPORT_LOCK: Mutex<()> = Mutex::new(());
+
+/// Helper function to serialize imports. The lock only guards Assimp's global state, so it's still
+/// usable after a panic.
+fn lock_imports() -> MutexGuard<'static, ()> {
+    IMPORT_LOCK.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+/// Helper function to find the last error message in captured log records.
+fn last_logged_error(records: &[LogRecord]) -> Option<String> {
+    records.iter()
+           .rev()
+           .find(|x| x.severity == Severity::Error)
+           .map(|x| x.message.clone())
+}
+
+/// Helper function to fetch the error string of the last failed import. Must be called while
+/// holding the import lock.
+fn last_error_string() -> String {
+    let error_str = unsafe { aiGetErrorString() };
+    if error_str.is_null() {
+        "Unknown error".to_owned()
+    } else {
+        let cstr = unsafe { CStr::from_ptr(error_str) };
+        String::from_utf8_lossy(cstr.to_bytes()).into_owned()
+    }
+}
+
+impl fmt::Debug for Importer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Importer")
+            .field("post_process_steps", &self.post_process_steps())
+            .field("properties", &self.properties)
+            .finish()
+    }
 }
 
 impl Drop for Importer {
@@ -10,6 +10,7 @@ use math::Matrix4x4;
 ///
 /// See `Importer::remove_component` for more details.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ComponentType {
     Normals,
     TangentsAndBitangents,
@@ -25,6 +26,7 @@ pub enum ComponentType {
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UVTransformFlag {
     Scaling,
     Rotation,
@@ -33,6 +35,7 @@ pub enum UVTransformFlag {
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PrimitiveType {
     Point,
     Line,
@@ -40,6 +43,84 @@ pub enum PrimitiveType {
     Polygon
 }
 
+/// Commonly used combinations of post-processing steps, matching Assimp's `aiProcess_*` presets.
+///
+/// See `ImporterBuilder::preset`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Preset {
+    /// Converts the data to the left-handed coordinate space used by Direct3D, enabling the
+    /// `make_left_handed`, `flip_uvs` and `flip_winding_order` steps.
+    ConvertToLeftHanded,
+    /// Default settings for real-time rendering, optimised for speed.
+    ///
+    /// Enables the `calc_tangent_space`, `generate_normals` (without smoothing),
+    /// `join_identical_vertices`, `triangulate`, `gen_uv_coords` and `sort_by_primitive_type`
+    /// steps.
+    TargetRealtimeFast,
+    /// Default settings for real-time rendering, with a good balance of speed and quality.
+    ///
+    /// Enables the `calc_tangent_space`, `generate_normals` (with smoothing),
+    /// `join_identical_vertices`, `improve_cache_locality`, `limit_bone_weights`,
+    /// `remove_redundant_materials`, `split_large_meshes`, `triangulate`, `gen_uv_coords`,
+    /// `sort_by_primitive_type`, `find_degenerates` and `find_invalid_data` steps.
+    TargetRealtimeQuality,
+    /// Default settings for real-time rendering, optimised for quality.
+    ///
+    /// Enables the same steps as `TargetRealtimeQuality`, plus the `find_instances`,
+    /// `validate_data_structure` and `optimize_meshes` steps.
+    TargetRealtimeMaxQuality
+}
+
+/// Enumerates the post-processing steps an `Importer` can run, see
+/// `Importer::post_process_steps`.
+///
+/// Each step is named after the `Importer` method which enables it, except for
+/// `GenerateNormals` and `GenerateSmoothNormals` which are both enabled by
+/// `Importer::generate_normals`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PostProcessStep {
+    CalcTangentSpace,
+    JoinIdenticalVertices,
+    MakeLeftHanded,
+    Triangulate,
+    RemoveComponent,
+    GenerateNormals,
+    GenerateSmoothNormals,
+    SplitLargeMeshes,
+    PreTransformVertices,
+    LimitBoneWeights,
+    ValidateDataStructure,
+    ImproveCacheLocality,
+    RemoveRedundantMaterials,
+    FixInfacingNormals,
+    SortByPrimitiveType,
+    FindDegenerates,
+    FindInvalidData,
+    GenUVCoords,
+    TransformUVCoords,
+    FindInstances,
+    OptimizeMeshes,
+    OptimizeGraph,
+    FlipUVs,
+    FlipWindingOrder,
+    SplitByBoneCount,
+    Debone
+}
+
+/// The value of an import property, see `Importer::properties`.
+///
+/// Assimp stores boolean properties as integers, so they are reported as `Int(0)` or `Int(1)`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PropertyValue {
+    Int(i32),
+    Float(f32),
+    Matrix(Matrix4x4),
+    String(String)
+}
+
 
 // Macro to simplify defining and structs and implementing Default trait
 // NOTE: pub keyword in field definition is to workaround rust issue #24189
@@ -48,6 +129,8 @@ macro_rules! struct_with_defaults {
         $($(#[$field_attr:meta])* pub $n:ident: $t:ty = $v:expr),*
     }) => (
         $(#[$struct_attr])*
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
         pub struct $i {
             /// Whether to enable the step. Default: false
             pub enable: bool,
@@ -62,6 +145,13 @@ macro_rules! struct_with_defaults {
                 }
             }
         }
+
+        impl $i {
+            /// Returns the default arguments, with the step enabled.
+            pub fn enabled() -> $i {
+                $i { enable: true, .. $i::default() }
+            }
+        }
     )
 }
 
@@ -14,10 +14,15 @@ macro_rules! define_iter {
 
         #[doc(hidden)]
         impl<'a> $name<'a> {
-            pub fn new(ptr: $raw, len: usize) -> $name<'a> {
+            // Unsafe as the caller must ensure the data outlives the chosen lifetime
+            pub unsafe fn new(ptr: $raw, len: usize) -> $name<'a> {
                 $name { ptr: ptr, idx: 0, len: len, _mk: ::std::marker::PhantomData }
             }
         }
+
+        // Iterators only read scene data, see the Send and Sync impls for Scene
+        unsafe impl<'a> Send for $name<'a> {}
+        unsafe impl<'a> Sync for $name<'a> {}
     )
 }
 
@@ -27,7 +32,7 @@ macro_rules! impl_iterator {
             type Item = $item<'a>;
             fn next(&mut self) -> Option<$item<'a>> {
                 if self.idx < self.len as isize {
-                    let item = $item::from_raw(unsafe { self.ptr.offset(self.idx) });
+                    let item = unsafe { $item::from_raw(self.ptr.offset(self.idx)) };
                     self.idx = self.idx + 1;
                     Some(item)
                 } else {
@@ -48,7 +53,7 @@ macro_rules! impl_iterator_indirect {
             type Item = $item<'a>;
             fn next(&mut self) -> Option<$item<'a>> {
                 if self.idx < self.len as isize {
-                    let item = $item::from_raw(unsafe { *self.ptr.offset(self.idx) });
+                    let item = unsafe { $item::from_raw(*self.ptr.offset(self.idx)) };
                     self.idx = self.idx + 1;
                     Some(item)
                 } else {
@@ -69,7 +74,7 @@ macro_rules! impl_iterator_pod {
             type Item = $item;
             fn next(&mut self) -> Option<$item> {
                 if self.idx < self.len as isize {
-                    let item = $item::from_raw(unsafe { self.ptr.offset(self.idx) });
+                    let item = unsafe { $item::from_raw(self.ptr.offset(self.idx)) };
                     self.idx = self.idx + 1;
                     Some(item)
                 } else {
@@ -88,8 +93,9 @@ macro_rules! define_type {
 
         #[doc(hidden)]
         impl<'a> $name<'a> {
-            pub fn from_raw(raw: *const $raw) -> $name<'a> {
-                unsafe { $name(&*raw) }
+            // Unsafe as the caller must ensure the data outlives the chosen lifetime
+            pub unsafe fn from_raw(raw: *const $raw) -> $name<'a> {
+                $name(&*raw)
             }
             pub fn to_raw(&self) -> *const $raw {
                 self.0
@@ -100,6 +106,10 @@ macro_rules! define_type {
             type Target = $raw;
             fn deref<'b>(&'b self) -> &'b $raw { &self.0 }
         }
+
+        // Views only read scene data, see the Send and Sync impls for Scene
+        unsafe impl<'a> Send for $name<'a> {}
+        unsafe impl<'a> Sync for $name<'a> {}
     );
     // Non-reference type = POD
     ($(#[$type_attr:meta])* struct $name:ident($raw:ty)) => (
@@ -108,8 +118,8 @@ macro_rules! define_type {
 
         #[doc(hidden)]
         impl $name {
-            pub fn from_raw(raw: *const $raw) -> $name {
-                unsafe { $name(*raw) }
+            pub unsafe fn from_raw(raw: *const $raw) -> $name {
+                $name(*raw)
             }
         }
 
@@ -149,3 +159,60 @@ macro_rules! define_type_and_iterator_indirect {
         impl_iterator_indirect!($iter_name, $type_name);
     );
 }
+
+// Implement the serde traits for a POD math type by converting it to and from an array.
+#[cfg(feature = "serde")]
+macro_rules! impl_serde_array {
+    ($name:ident, $array:ty) => (
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S)
+                                                 -> Result<S::Ok, S::Error> {
+                let array: $array = (*self).into();
+                ::serde::Serialize::serialize(&array, serializer)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<$name, D::Error>
+                where D: ::serde::Deserializer<'de>
+            {
+                let array: $array = try!(::serde::Deserialize::deserialize(deserializer));
+                Ok($name::from(array))
+            }
+        }
+    )
+}
+
+// Implement component-wise addition and subtraction, and multiplication and division by a
+// scalar, for a POD math type.
+macro_rules! impl_component_ops {
+    ($name:ident { $($field:ident),* }) => (
+        impl ::std::ops::Add for $name {
+            type Output = $name;
+            fn add(self, rhs: $name) -> $name {
+                $name::new($(self.$field + rhs.$field),*)
+            }
+        }
+
+        impl ::std::ops::Sub for $name {
+            type Output = $name;
+            fn sub(self, rhs: $name) -> $name {
+                $name::new($(self.$field - rhs.$field),*)
+            }
+        }
+
+        impl ::std::ops::Mul<f32> for $name {
+            type Output = $name;
+            fn mul(self, rhs: f32) -> $name {
+                $name::new($(self.$field * rhs),*)
+            }
+        }
+
+        impl ::std::ops::Div<f32> for $name {
+            type Output = $name;
+            fn div(self, rhs: f32) -> $name {
+                $name::new($(self.$field / rhs),*)
+            }
+        }
+    )
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::io::{Read, Seek};
+use std::path::PathBuf;
+
+use zip::ZipArchive;
+use zip::result::ZipResult;
+
+use super::{normalize_path, File, FileSystem, MemoryFile};
+
+/// A file system serving files from a zip archive.
+///
+/// Entries are decompressed into memory when they are opened.
+///
+/// # Examples
+/// ```no_run
+/// use std::fs::File;
+/// use assimp::Importer;
+/// use assimp::io::ZipFileSystem;
+///
+/// let archive = File::open("assets.zip").unwrap();
+/// let mut fs = ZipFileSystem::new(archive).unwrap();
+///
+/// let importer = Importer::new();
+/// let scene = importer.read_file_with_io("models/spider.obj", &mut fs);
+/// ```
+pub struct ZipFileSystem<R: Read + Seek> {
+    archive: ZipArchive<R>,
+    // Normalized paths to the index of their entry in the archive
+    entries: HashMap<PathBuf, usize>
+}
+
+impl<R: Read + Seek> ZipFileSystem<R> {
+    /// Create a file system reading from the zip archive contained in `reader`.
+    pub fn new(reader: R) -> ZipResult<ZipFileSystem<R>> {
+        let mut archive = try!(ZipArchive::new(reader));
+        let mut entries = HashMap::new();
+        for i in 0..archive.len() {
+            let entry = try!(archive.by_index(i));
+            if !entry.name().ends_with('/') {
+                entries.insert(normalize_path(entry.name()), i);
+            }
+        }
+
+        Ok(ZipFileSystem {
+            archive: archive,
+            entries: entries
+        })
+    }
+
+    /// Consume the file system, returning the underlying archive.
+    pub fn into_inner(self) -> ZipArchive<R> {
+        self.archive
+    }
+
+    fn resolve(&self, path: &str) -> Option<PathBuf> {
+        let path = normalize_path(path);
+        if self.entries.contains_key(&path) { Some(path) } else { None }
+    }
+}
+
+impl<R: Read + Seek> FileSystem for ZipFileSystem<R> {
+    fn exists(&self, path: &str) -> bool {
+        self.resolve(path).is_some()
+    }
+
+    fn open(&mut self, path: &str) -> Option<Box<File>> {
+        let path = match self.resolve(path) {
+            Some(path) => path,
+            None => return None
+        };
+
+        let mut data = Vec::new();
+        {
+            let mut entry = match self.archive.by_index(self.entries[&path]) {
+                Ok(entry) => entry,
+                Err(_) => return None
+            };
+            if entry.read_to_end(&mut data).is_err() {
+                return None;
+            }
+        }
+
+        Some(Box::new(MemoryFile::new(data)))
+    }
+}
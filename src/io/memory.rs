@@ -0,0 +1,127 @@
+use std::cmp;
+use std::collections::HashMap;
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+
+use super::{normalize_path, File, FileSystem};
+
+/// A file backed by an in-memory buffer.
+pub struct MemoryFile {
+    data: Vec<u8>,
+    pos: u64
+}
+
+impl MemoryFile {
+    /// Create a new file containing `data`.
+    pub fn new(data: Vec<u8>) -> MemoryFile {
+        MemoryFile { data: data, pos: 0 }
+    }
+}
+
+impl File for MemoryFile {
+    fn read(&mut self, buf: &mut [u8]) -> usize {
+        let start = cmp::min(self.pos, self.data.len() as u64) as usize;
+        let len = cmp::min(buf.len(), self.data.len() - start);
+        buf[..len].copy_from_slice(&self.data[start..start + len]);
+        self.pos += len as u64;
+        len
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> bool {
+        let new_pos = match pos {
+            SeekFrom::Start(x) => x as i64,
+            SeekFrom::Current(x) => self.pos as i64 + x,
+            SeekFrom::End(x) => self.data.len() as i64 + x
+        };
+        if new_pos >= 0 && new_pos <= self.data.len() as i64 {
+            self.pos = new_pos as u64;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn tell(&self) -> u64 {
+        self.pos
+    }
+
+    fn size(&self) -> u64 {
+        self.data.len() as u64
+    }
+}
+
+/// A file system serving files from a map of paths to in-memory buffers.
+///
+/// Useful for hermetic test fixtures, or for assets which have already been loaded into memory.
+///
+/// # Examples
+/// ```
+/// use assimp::Importer;
+/// use assimp::io::MemoryFileSystem;
+///
+/// let mut fs = MemoryFileSystem::new();
+/// fs.insert("models/triangle.obj", "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n");
+///
+/// let importer = Importer::new();
+/// let scene = importer.read_file_with_io("models/triangle.obj", &mut fs);
+/// ```
+pub struct MemoryFileSystem {
+    files: HashMap<PathBuf, Vec<u8>>
+}
+
+impl MemoryFileSystem {
+    /// Create a new, empty file system.
+    pub fn new() -> MemoryFileSystem {
+        MemoryFileSystem {
+            files: HashMap::new()
+        }
+    }
+
+    /// Add a file to the file system, returning the previous contents if the file already
+    /// existed.
+    pub fn insert<P: AsRef<Path>, D: Into<Vec<u8>>>(&mut self, path: P, data: D) -> Option<Vec<u8>> {
+        let path = normalize_path(&path.as_ref().to_string_lossy());
+        self.files.insert(path, data.into())
+    }
+
+    /// Remove a file from the file system, returning its contents if it existed.
+    pub fn remove<P: AsRef<Path>>(&mut self, path: P) -> Option<Vec<u8>> {
+        let path = normalize_path(&path.as_ref().to_string_lossy());
+        self.files.remove(&path)
+    }
+
+    /// Returns the contents of the file at `path`, if it exists.
+    pub fn get<P: AsRef<Path>>(&self, path: P) -> Option<&[u8]> {
+        let path = normalize_path(&path.as_ref().to_string_lossy());
+        self.files.get(&path).map(|x| &x[..])
+    }
+
+    fn resolve(&self, path: &str) -> Option<PathBuf> {
+        let path = normalize_path(path);
+        if self.files.contains_key(&path) { Some(path) } else { None }
+    }
+}
+
+impl From<HashMap<PathBuf, Vec<u8>>> for MemoryFileSystem {
+    fn from(files: HashMap<PathBuf, Vec<u8>>) -> MemoryFileSystem {
+        let mut fs = MemoryFileSystem::new();
+        for (path, data) in files {
+            fs.insert(path, data);
+        }
+        fs
+    }
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn exists(&self, path: &str) -> bool {
+        self.resolve(path).is_some()
+    }
+
+    fn open(&mut self, path: &str) -> Option<Box<File>> {
+        let path = match self.resolve(path) {
+            Some(path) => path,
+            None => return None
+        };
+        Some(Box::new(MemoryFile::new(self.files[&path].clone())))
+    }
+}
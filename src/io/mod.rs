@@ -0,0 +1,298 @@
+//! The `io` module lets Assimp read files through a custom file system.
+//!
+//! Assimp normally reads files straight from disk. Formats made up of several files (e.g. OBJ
+//! with MTL materials, glTF with external buffers) request the additional files by path during
+//! import, so importing from memory isn't enough for them. Implementing the `FileSystem` and
+//! `File` traits allows those requests to be served from archives, memory or anywhere else.
+//!
+//! # Examples
+//! ```no_run
+//! use std::io::SeekFrom;
+//! use assimp::Importer;
+//! use assimp::io::{File, FileSystem};
+//!
+//! struct BufferFile(Vec<u8>, usize);
+//!
+//! impl File for BufferFile {
+//!     fn read(&mut self, buf: &mut [u8]) -> usize {
+//!         let n = ::std::cmp::min(buf.len(), self.0.len() - self.1);
+//!         buf[..n].copy_from_slice(&self.0[self.1..self.1 + n]);
+//!         self.1 += n;
+//!         n
+//!     }
+//!     fn seek(&mut self, pos: SeekFrom) -> bool {
+//!         match pos {
+//!             SeekFrom::Start(x) if x as usize <= self.0.len() => { self.1 = x as usize; true }
+//!             _ => false
+//!         }
+//!     }
+//!     fn tell(&self) -> u64 { self.1 as u64 }
+//!     fn size(&self) -> u64 { self.0.len() as u64 }
+//! }
+//!
+//! struct SingleFileSystem;
+//!
+//! impl FileSystem for SingleFileSystem {
+//!     fn exists(&self, path: &str) -> bool { path == "box.obj" }
+//!     fn open(&mut self, _path: &str) -> Option<Box<File>> {
+//!         let data = b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n";
+//!         Some(Box::new(BufferFile(data.to_vec(), 0)))
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let importer = Importer::new();
+//!     let scene = importer.read_file_with_io("box.obj", &mut SingleFileSystem);
+//! }
+//! ```
+//!
+//! Two ready-made file systems are provided: `MemoryFileSystem`, which serves files from a map
+//! of paths to buffers, and `ZipFileSystem` (requires the `zip` feature), which serves files from
+//! a zip archive. Both resolve paths lexically, so `models/../textures/a.png` and
+//! `textures\a.png` both find `textures/a.png`.
+//!
+//! During an import, paths which don't exist are retried relative to the directories of files
+//! opened earlier in the same import, so references which Assimp passes through unmodified still
+//! resolve against the file referencing them. Nothing is carried over between imports.
+
+use std::ffi::CStr;
+use std::io::SeekFrom;
+use std::marker::PhantomData;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::ptr;
+use std::slice;
+
+use ffi::*;
+use libc::{c_char, size_t};
+
+pub use self::memory::{MemoryFile, MemoryFileSystem};
+#[cfg(feature = "zip")]
+pub use self::archive::ZipFileSystem;
+
+mod memory;
+#[cfg(feature = "zip")]
+mod archive;
+
+/// A file opened by a `FileSystem`.
+pub trait File {
+    /// Read up to `buf.len()` bytes into `buf`, returning the number of bytes read. Returning 0
+    /// indicates that the end of the file has been reached.
+    fn read(&mut self, buf: &mut [u8]) -> usize;
+
+    /// Move the read position of the file. Returns false if the position is invalid.
+    fn seek(&mut self, pos: SeekFrom) -> bool;
+
+    /// Returns the current read position in the file.
+    fn tell(&self) -> u64;
+
+    /// Returns the total size of the file in bytes.
+    fn size(&self) -> u64;
+}
+
+/// A virtual file system which Assimp reads files from.
+///
+/// Paths are passed through as Assimp requests them. For files referenced by other files, this is
+/// usually the referenced path joined onto the directory of the referencing file. If no file
+/// exists at a requested path, it's also looked up relative to the directories of files opened
+/// earlier in the same import. Assimp only ever opens files for reading when importing.
+pub trait FileSystem {
+    /// Returns true if a file exists at `path`.
+    fn exists(&self, path: &str) -> bool;
+
+    /// Open the file at `path` for reading. Returns `None` if the file can't be opened.
+    fn open(&mut self, path: &str) -> Option<Box<File>>;
+
+    /// Close a file previously returned by `open`.
+    ///
+    /// The default implementation simply drops the file.
+    fn close(&mut self, file: Box<File>) {
+        drop(file)
+    }
+}
+
+/// Normalize a path so it can be used as a key, unifying separators and lexically resolving `.`
+/// and `..` components. Leading separators are dropped, so absolute paths are relative to the
+/// root of the file system.
+fn normalize_path(path: &str) -> PathBuf {
+    let mut components = Vec::new();
+    for component in path.split(|c| c == '/' || c == '\\') {
+        match component {
+            "" | "." => {}
+            ".." => { components.pop(); }
+            _ => components.push(component)
+        }
+    }
+    components.iter().collect()
+}
+
+/// Resolves paths requested by Assimp during a single import.
+struct ImportFileSystem<'a> {
+    fs: &'a mut FileSystem,
+    // Directories of files opened so far, most recently opened last
+    dirs: Vec<String>
+}
+
+impl<'a> ImportFileSystem<'a> {
+    /// Find a path which exists in the file system, trying `path` itself first.
+    fn resolve(&self, path: &str) -> Option<String> {
+        if self.fs.exists(path) {
+            return Some(path.to_owned());
+        }
+        self.dirs.iter()
+                 .rev()
+                 .map(|dir| format!("{}/{}", dir, path))
+                 .find(|x| self.fs.exists(x))
+    }
+
+    fn open(&mut self, path: &str) -> Option<Box<File>> {
+        let path = match self.resolve(path) {
+            Some(path) => path,
+            None => return None
+        };
+        let file = self.fs.open(&path);
+        if file.is_some() {
+            if let Some(i) = path.rfind(|c| c == '/' || c == '\\') {
+                let dir = &path[..i];
+                if !self.dirs.iter().any(|x| x == dir) {
+                    self.dirs.push(dir.to_owned());
+                }
+            }
+        }
+        file
+    }
+}
+
+/// Bridges a `FileSystem` to Assimp's `aiFileIO` callbacks for the duration of an import.
+#[doc(hidden)]
+pub struct FileIO<'a> {
+    raw: AiFileIO,
+    _mk: PhantomData<ImportFileSystem<'a>>
+}
+
+impl<'a> FileIO<'a> {
+    pub fn new(fs: &'a mut FileSystem) -> FileIO<'a> {
+        let fs = Box::into_raw(Box::new(ImportFileSystem { fs: fs, dirs: Vec::new() }));
+        FileIO {
+            raw: AiFileIO {
+                open_proc: Some(io_open),
+                close_proc: Some(io_close),
+                user_data: fs as *const c_char
+            },
+            _mk: PhantomData
+        }
+    }
+
+    pub fn as_raw(&mut self) -> *mut AiFileIO {
+        &mut self.raw
+    }
+}
+
+impl<'a> Drop for FileIO<'a> {
+    fn drop(&mut self) {
+        unsafe { drop(Box::from_raw(self.raw.user_data as *mut ImportFileSystem<'a>)); }
+    }
+}
+
+unsafe fn file_system<'a>(io: *mut AiFileIO) -> &'a mut ImportFileSystem<'a> {
+    &mut *((*io).user_data as *mut ImportFileSystem)
+}
+
+unsafe fn file<'a>(file: *mut AiFile) -> &'a mut Box<File> {
+    &mut *((*file).user_data as *mut Box<File>)
+}
+
+// Panics can't unwind into Assimp, so every callback catches them and reports a failure instead.
+// For reads this looks like the end of the file.
+unsafe extern "system" fn io_open(io: *mut AiFileIO,
+                                  path: *const c_char,
+                                  mode: *const c_char) -> *mut AiFile {
+    let fs = file_system(io);
+    let path = CStr::from_ptr(path).to_string_lossy();
+
+    // Only reading is supported
+    let mode = CStr::from_ptr(mode).to_bytes();
+    if mode.iter().any(|&c| c == b'w' || c == b'a' || c == b'+') {
+        return ptr::null_mut();
+    }
+
+    match panic::catch_unwind(AssertUnwindSafe(|| fs.open(&path))) {
+        Ok(Some(file)) => {
+            let file = Box::into_raw(Box::new(file));
+            Box::into_raw(Box::new(AiFile {
+                read_proc: Some(file_read),
+                write_proc: Some(file_write),
+                tell_proc: Some(file_tell),
+                file_size_proc: Some(file_size),
+                seek_proc: Some(file_seek),
+                flush_proc: Some(file_flush),
+                user_data: file as *const c_char
+            }))
+        }
+        _ => ptr::null_mut()
+    }
+}
+
+unsafe extern "system" fn io_close(io: *mut AiFileIO, file: *mut AiFile) {
+    let fs = file_system(io);
+    let raw_file = Box::from_raw(file);
+    let file = Box::from_raw(raw_file.user_data as *mut Box<File>);
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| fs.fs.close(*file)));
+}
+
+unsafe extern "system" fn file_read(raw_file: *mut AiFile,
+                                    buffer: *mut c_char,
+                                    size: size_t,
+                                    count: size_t) -> size_t {
+    let len = match size.checked_mul(count) {
+        Some(len) if len > 0 => len,
+        _ => return 0
+    };
+    let buf = slice::from_raw_parts_mut(buffer as *mut u8, len);
+    let file = file(raw_file);
+
+    // Same semantics as fread, keep reading until the buffer is full or the file is exhausted
+    let mut total = 0;
+    while total < len {
+        match panic::catch_unwind(AssertUnwindSafe(|| file.read(&mut buf[total..]))) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => total += n
+        }
+    }
+    total / size
+}
+
+unsafe extern "system" fn file_write(_: *mut AiFile,
+                                     _: *const c_char,
+                                     _: size_t,
+                                     _: size_t) -> size_t {
+    0
+}
+
+unsafe extern "system" fn file_tell(raw_file: *mut AiFile) -> size_t {
+    let file = file(raw_file);
+    panic::catch_unwind(AssertUnwindSafe(|| file.tell() as size_t)).unwrap_or(0)
+}
+
+unsafe extern "system" fn file_size(raw_file: *mut AiFile) -> size_t {
+    let file = file(raw_file);
+    panic::catch_unwind(AssertUnwindSafe(|| file.size() as size_t)).unwrap_or(0)
+}
+
+unsafe extern "system" fn file_seek(raw_file: *mut AiFile,
+                                    offset: size_t,
+                                    origin: AiOrigin) -> AiReturn {
+    // Relative offsets are passed as unsigned values, reinterpret them as signed
+    let pos = match origin {
+        AiOrigin::Set => SeekFrom::Start(offset as u64),
+        AiOrigin::Cur => SeekFrom::Current(offset as isize as i64),
+        AiOrigin::End => SeekFrom::End(offset as isize as i64)
+    };
+    let file = file(raw_file);
+    match panic::catch_unwind(AssertUnwindSafe(|| file.seek(pos))) {
+        Ok(true) => AiReturn::Success,
+        _ => AiReturn::Failure
+    }
+}
+
+unsafe extern "system" fn file_flush(_: *mut AiFile) {}
@@ -4,18 +4,36 @@
 
 extern crate assimp_sys as ffi;
 extern crate cgmath;
+#[cfg(feature = "glam")]
+extern crate glam;
 extern crate libc;
+#[cfg(feature = "log")]
+#[macro_use]
+extern crate log as log_crate;
+#[cfg(feature = "mint")]
+extern crate mint;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[cfg(feature = "zip")]
+extern crate zip;
 
-pub use import::Importer;
-pub use log::LogStream;
+pub use import::{ImportConfig, ImportError, Importer, ImporterBuilder};
+pub use log::{AttachError, LogRecord, LogStream, LogStreamGuard, Severity};
 pub use math::{Color3D, Color4D, Matrix3x3, Matrix4x4, Quaternion, Vector2D, Vector3D};
+pub use owned::{OwnedScene, SceneBuilder};
 pub use scene::{Animation, NodeAnim, VectorKey, QuatKey, Camera, Face, Light, Material, Mesh, Node, Scene, Texture};
 
 #[macro_use]
 mod internal_macros;
 
+pub mod cache;
 pub mod export;
 pub mod import;
+pub mod io;
 pub mod log;
 pub mod math;
+pub mod owned;
 pub mod scene;
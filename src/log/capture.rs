@@ -0,0 +1,51 @@
+//! Captures messages logged by Assimp on the current thread.
+//!
+//! Assimp calls log streams on the thread which logged the message, i.e. the thread performing
+//! the import, so each message is routed to a buffer local to that thread. This keeps messages
+//! from concurrent imports on other threads separate.
+
+use std::cell::RefCell;
+
+use super::LogRecord;
+
+thread_local!(static RECORDS: RefCell<Option<Vec<LogRecord>>> = RefCell::new(None));
+
+/// Run `f`, returning its result along with all messages logged by Assimp on the current thread
+/// while it was running.
+pub fn capture<T, F: FnOnce() -> T>(f: F) -> (T, Vec<LogRecord>) {
+    super::attach_dispatcher();
+
+    // Restores the previous buffer even if `f` panics, so captures can be nested
+    struct Restore(Option<Option<Vec<LogRecord>>>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take().unwrap();
+            RECORDS.with(|records| *records.borrow_mut() = previous);
+        }
+    }
+
+    let previous = RECORDS.with(|records| records.borrow_mut().replace(Vec::new()));
+    let restore = Restore(Some(previous));
+    let result = f();
+    let captured = RECORDS.with(|records| records.borrow_mut().take()).unwrap_or_default();
+    drop(restore);
+    // The messages were also logged while any enclosing capture was running
+    RECORDS.with(|records| {
+        if let Some(ref mut outer) = *records.borrow_mut() {
+            outer.extend(captured.iter().cloned());
+        }
+    });
+    (result, captured)
+}
+
+/// Add a message to the buffer of the current thread, if it is capturing messages.
+pub fn route_message(record: &LogRecord) {
+    // Ignore errors accessing the buffer during thread shutdown
+    let _ = RECORDS.try_with(|records| {
+        if let Ok(mut records) = records.try_borrow_mut() {
+            if let Some(ref mut records) = *records {
+                records.push(record.clone());
+            }
+        }
+    });
+}
@@ -0,0 +1,42 @@
+//! Error type returned when attaching a log stream fails.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Describes why a `LogStream` couldn't be attached.
+#[derive(Debug)]
+pub enum AttachError {
+    /// The stream is already attached.
+    AlreadyAttached,
+    /// The file of a file stream couldn't be created.
+    Io(io::Error)
+}
+
+impl From<io::Error> for AttachError {
+    fn from(err: io::Error) -> AttachError {
+        AttachError::Io(err)
+    }
+}
+
+impl fmt::Display for AttachError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AttachError::AlreadyAttached => write!(f, "log stream is already attached"),
+            AttachError::Io(ref err) => write!(f, "failed to create log file: {}", err)
+        }
+    }
+}
+
+impl Error for AttachError {
+    fn description(&self) -> &str {
+        "failed to attach log stream"
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            AttachError::Io(ref err) => Some(err),
+            AttachError::AlreadyAttached => None
+        }
+    }
+}
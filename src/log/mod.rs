@@ -1,48 +1,304 @@
-use std::ffi::CString;
+//! The `log` module contains streams which messages logged by Assimp are written to.
+
+use std::cell::Cell;
+use std::ffi::CStr;
+use std::fs;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
 use std::ptr;
+use std::sync::{Arc, Mutex, Once};
 
 use ffi::*;
+use libc::c_char;
+
+#[doc(hidden)]
+pub use self::capture::capture;
+pub use self::error::AttachError;
+
+mod capture;
+mod error;
+
+/// Severity of a message logged by Assimp.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    /// Debug messages, only logged when verbose logging is enabled.
+    Debug,
+    /// Information about the import process.
+    Info,
+    /// Potential problems with the imported data.
+    Warn,
+    /// Errors, usually causing the import to fail.
+    Error
+}
+
+/// A single message logged by Assimp.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LogRecord {
+    /// Severity of the message.
+    pub severity: Severity,
+    /// The message, with Assimp's severity and thread prefix removed.
+    pub message: String
+}
+
+impl LogRecord {
+    /// Parse a message as passed to log streams by Assimp, e.g. "Warn,  T0: message\n".
+    ///
+    /// Messages without a recognised severity prefix are treated as `Info`.
+    #[doc(hidden)]
+    pub fn parse(raw: &str) -> LogRecord {
+        let raw = raw.trim_right_matches(|c| c == '\n' || c == '\r');
+        let (severity, rest) = match raw.find(',') {
+            Some(idx) => {
+                let severity = match &raw[..idx] {
+                    "Debug" => Some(Severity::Debug),
+                    "Info" => Some(Severity::Info),
+                    "Warn" => Some(Severity::Warn),
+                    "Error" => Some(Severity::Error),
+                    _ => None
+                };
+                (severity, &raw[idx + 1..])
+            }
+            None => (None, raw)
+        };
+
+        match severity {
+            Some(severity) => LogRecord {
+                severity: severity,
+                message: strip_thread_id(rest).to_owned()
+            },
+            None => LogRecord {
+                severity: Severity::Info,
+                message: raw.to_owned()
+            }
+        }
+    }
+}
+
+/// Strip the thread id which follows the severity, e.g. "  T0: message" becomes "message".
+fn strip_thread_id(message: &str) -> &str {
+    let message = message.trim_left();
+    if message.starts_with('T') {
+        if let Some(idx) = message.find(": ") {
+            if message[1..idx].chars().all(|c| c.is_digit(10)) {
+                return &message[idx + 2..];
+            }
+        }
+    }
+    message
+}
+
+type Callback = Mutex<Box<FnMut(Severity, &str) + Send>>;
+
+/// Where a log stream writes messages to.
+enum Target {
+    File(PathBuf, Mutex<Option<fs::File>>),
+    StdOut,
+    StdErr,
+    #[cfg(windows)]
+    Debugger,
+    Callback(Callback)
+}
+
+impl Target {
+    fn write(&self, raw: &str, record: &LogRecord) {
+        // Write errors are ignored, as Assimp does for its own streams
+        match *self {
+            Target::File(_, ref file) => {
+                if let Ok(mut file) = file.lock() {
+                    if let Some(ref mut file) = *file {
+                        let _ = file.write_all(raw.as_bytes());
+                    }
+                }
+            }
+            Target::StdOut => { let _ = io::stdout().write_all(raw.as_bytes()); }
+            Target::StdErr => { let _ = io::stderr().write_all(raw.as_bytes()); }
+            #[cfg(windows)]
+            Target::Debugger => {
+                if let Ok(cstr) = ::std::ffi::CString::new(raw) {
+                    unsafe { OutputDebugStringA(cstr.as_ptr()) }
+                }
+            }
+            Target::Callback(ref callback) => {
+                // A callback which panicked poisons the lock, and isn't called again
+                if let Ok(mut callback) = callback.lock() {
+                    (&mut **callback)(record.severity, &record.message);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(windows)]
+extern "system" {
+    fn OutputDebugStringA(output: *const c_char);
+}
+
+// Assimp keys attached streams by their callback and user pointers using a comparison which treats
+// many distinct streams as equal, so attaching and detaching several streams through it
+// misbehaves. Instead a single stream is attached to Assimp the first time it's needed, which
+// dispatches messages to the attached `LogStream`s.
+static ATTACH_DISPATCHER: Once = Once::new();
+static ATTACHED_TARGETS: Mutex<Vec<Arc<Target>>> = Mutex::new(Vec::new());
 
+fn attach_dispatcher() {
+    ATTACH_DISPATCHER.call_once(|| {
+        let raw = AiLogStream { callback: Some(dispatch_message), user: ptr::null_mut() };
+        unsafe { aiAttachLogStream(&raw) }
+    });
+}
+
+unsafe extern "system" fn dispatch_message(message: *const c_char, _: *mut c_char) {
+    if message.is_null() {
+        return;
+    }
+    let raw = CStr::from_ptr(message).to_string_lossy();
+    // Panics can't unwind into Assimp, so they're caught and the message is dropped
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+        let record = LogRecord::parse(&raw);
+        capture::route_message(&record);
+
+        // Don't hold the lock while writing, a callback may attach or detach streams
+        let targets = match ATTACHED_TARGETS.lock() {
+            Ok(targets) => targets.clone(),
+            Err(_) => return
+        };
+        for target in targets {
+            target.write(&raw, &record);
+        }
+    }));
+}
+
+/// A stream which messages logged by Assimp are written to.
+///
+/// Messages are only written to a stream while it is attached. Attaching a stream returns a
+/// `LogStreamGuard` which detaches the stream again when dropped.
+///
+/// # Examples
+/// ```no_run
+/// use assimp::{Importer, LogStream};
+///
+/// let log_stream = LogStream::stdout();
+/// {
+///     let _guard = log_stream.attach().unwrap();
+///     let importer = Importer::new();
+///     let scene = importer.read_file("examples/box.obj");
+/// }
+/// // The stream is detached here
+/// ```
 pub struct LogStream {
-    raw: AiLogStream,
-    attached: bool
+    target: Arc<Target>,
+    attached: Cell<bool>
 }
 
 impl LogStream {
+    /// Create a log stream which writes to a file. The file is created, or truncated, each time
+    /// the stream is attached. Returns `None` if `filename` is empty.
     pub fn file(filename: &str) -> Option<LogStream> {
-        let cstr = CString::new(filename).unwrap().as_ptr();
-        let stream = unsafe { aiGetPredefinedLogStream(AiDefaultLogStream::File, cstr) };
-        if stream.callback.is_some() {
-            Some(LogStream { raw: stream, attached: false })
-        } else {
+        if filename.is_empty() {
             None
+        } else {
+            Some(LogStream::new(Target::File(PathBuf::from(filename), Mutex::new(None))))
         }
     }
 
+    /// Create a log stream which writes to the standard output.
     pub fn stdout() -> LogStream {
-        let stream = unsafe { aiGetPredefinedLogStream(AiDefaultLogStream::StdOut, ptr::null()) };
-        LogStream { raw: stream, attached: false }
+        LogStream::new(Target::StdOut)
     }
 
+    /// Create a log stream which writes to the standard error output.
     pub fn stderr() -> LogStream {
-        let stream = unsafe { aiGetPredefinedLogStream(AiDefaultLogStream::StdErr, ptr::null()) };
-        LogStream { raw: stream, attached: false }
+        LogStream::new(Target::StdErr)
+    }
+
+    /// Create a log stream which calls `callback` with each message logged by Assimp.
+    ///
+    /// The message is passed without Assimp's severity and thread prefix or the trailing
+    /// newline. Assimp calls log streams on the thread which logged the message, so the closure
+    /// may be called from several threads, but never concurrently. The closure is kept alive
+    /// for as long as the stream.
+    pub fn callback<F>(callback: F) -> LogStream
+        where F: FnMut(Severity, &str) + Send + 'static
+    {
+        LogStream::new(Target::Callback(Mutex::new(Box::new(callback))))
     }
 
+    /// Create a log stream which forwards messages to the [`log`](https://docs.rs/log) crate.
+    ///
+    /// Messages are logged with the target "assimp", at the level matching their severity. Debug
+    /// messages are only logged by Assimp if verbose logging is enabled with
+    /// `set_verbose_logging`.
+    ///
+    /// Requires the `log` feature.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use assimp::{Importer, LogStream};
+    ///
+    /// let log_stream = LogStream::log_facade();
+    /// let _guard = log_stream.attach().unwrap();
+    ///
+    /// // Messages are forwarded for as long as the guard is alive
+    /// let importer = Importer::new();
+    /// let scene = importer.read_file("examples/box.obj");
+    /// ```
+    #[cfg(feature = "log")]
+    pub fn log_facade() -> LogStream {
+        LogStream::callback(|severity, message| {
+            let level = match severity {
+                Severity::Debug => ::log_crate::Level::Debug,
+                Severity::Info => ::log_crate::Level::Info,
+                Severity::Warn => ::log_crate::Level::Warn,
+                Severity::Error => ::log_crate::Level::Error
+            };
+            log!(target: "assimp", level, "{}", message);
+        })
+    }
+
+    /// Create a log stream which writes to the debugger.
     #[cfg(windows)]
     pub fn debug() -> LogStream {
-        let stream = unsafe { aiGetPredefinedLogStream(AiDefaultLogStream::Debugger, ptr::null()) };
-        LogStream { raw: stream, attached: false }
+        LogStream::new(Target::Debugger)
     }
 
-    pub fn attached(&self) -> bool { self.attached }
+    fn new(target: Target) -> LogStream {
+        LogStream { target: Arc::new(target), attached: Cell::new(false) }
+    }
 
-    pub fn attach(&mut self) {
-        if !self.attached { unsafe { aiAttachLogStream(&self.raw) } }
+    /// Returns true if the stream is attached.
+    pub fn attached(&self) -> bool {
+        self.attached.get()
     }
 
-    pub fn detach(&mut self) {
-        if self.attached { unsafe { aiDetachLogStream(&self.raw); } }
+    /// Attach the stream, so messages logged by Assimp are written to it.
+    ///
+    /// Returns a guard which detaches the stream when dropped. Fails if the stream is already
+    /// attached, or its file can't be created. Streams can be detached in any order, and
+    /// attached again once detached.
+    pub fn attach(&self) -> Result<LogStreamGuard, AttachError> {
+        if self.attached() {
+            return Err(AttachError::AlreadyAttached);
+        }
+        if let Target::File(ref path, ref file) = *self.target {
+            *file.lock().unwrap() = Some(try!(fs::File::create(path)));
+        }
+
+        attach_dispatcher();
+        ATTACHED_TARGETS.lock().unwrap().push(self.target.clone());
+        self.attached.set(true);
+        Ok(LogStreamGuard { stream: self })
+    }
+
+    fn detach(&self) {
+        if !self.attached() {
+            return;
+        }
+        ATTACHED_TARGETS.lock().unwrap().retain(|x| !Arc::ptr_eq(x, &self.target));
+        if let Target::File(_, ref file) = *self.target {
+            *file.lock().unwrap() = None;
+        }
+        self.attached.set(false);
     }
 
     pub fn set_verbose_logging(state: bool) {
@@ -52,6 +308,19 @@ impl LogStream {
 
 impl Drop for LogStream {
     fn drop(&mut self) {
+        // Only still attached if the guard was leaked
         self.detach()
     }
 }
+
+/// Keeps a `LogStream` attached, detaching it when dropped.
+#[must_use = "the log stream is detached when the guard is dropped"]
+pub struct LogStreamGuard<'a> {
+    stream: &'a LogStream
+}
+
+impl<'a> Drop for LogStreamGuard<'a> {
+    fn drop(&mut self) {
+        self.stream.detach()
+    }
+}
@@ -4,6 +4,7 @@ use ffi::AiColor3D;
 define_type! {
     /// Color3D docs
     #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
     struct Color3D(AiColor3D)
 }
 
@@ -13,6 +14,8 @@ impl Color3D {
     }
 }
 
+impl_component_ops!(Color3D { r, g, b });
+
 impl From<[f32; 3]> for Color3D {
     fn from(v: [f32; 3]) -> Color3D {
         Color3D::new(v[0], v[1], v[2])
@@ -25,6 +28,9 @@ impl Into<[f32; 3]> for Color3D {
     }
 }
 
+#[cfg(feature = "serde")]
+impl_serde_array!(Color3D, [f32; 3]);
+
 impl From<Vector3<f32>> for Color3D {
     fn from(p: Vector3<f32>) -> Color3D {
         Color3D::new(p[0], p[1], p[2])
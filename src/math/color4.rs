@@ -4,6 +4,7 @@ use ffi::AiColor4D;
 define_type! {
     /// Color4D docs
     #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
     struct Color4D(AiColor4D)
 }
 
@@ -13,6 +14,8 @@ impl Color4D {
     }
 }
 
+impl_component_ops!(Color4D { r, g, b, a });
+
 impl From<[f32; 4]> for Color4D {
     fn from(v: [f32; 4]) -> Color4D {
         Color4D::new(v[0], v[1], v[2], v[3])
@@ -25,6 +28,9 @@ impl Into<[f32; 4]> for Color4D {
     }
 }
 
+#[cfg(feature = "serde")]
+impl_serde_array!(Color4D, [f32; 4]);
+
 impl From<Vector4<f32>> for Color4D {
     fn from(p: Vector4<f32>) -> Color4D {
         Color4D::new(p[0], p[1], p[2], p[3])
@@ -0,0 +1,105 @@
+//! Conversions to and from the `glam` types, enabled by the `glam` feature.
+//!
+//! Colors convert to vectors, as `glam` has no color types.
+
+use glam::{Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+
+use super::*;
+
+impl From<Vec2> for Vector2D {
+    fn from(v: Vec2) -> Vector2D {
+        Vector2D::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2D> for Vec2 {
+    fn from(v: Vector2D) -> Vec2 {
+        Vec2::new(v.x, v.y)
+    }
+}
+
+impl From<Vec3> for Vector3D {
+    fn from(v: Vec3) -> Vector3D {
+        Vector3D::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3D> for Vec3 {
+    fn from(v: Vector3D) -> Vec3 {
+        Vec3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3A> for Vector3D {
+    fn from(v: Vec3A) -> Vector3D {
+        Vector3D::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3D> for Vec3A {
+    fn from(v: Vector3D) -> Vec3A {
+        Vec3A::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3> for Color3D {
+    fn from(v: Vec3) -> Color3D {
+        Color3D::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Color3D> for Vec3 {
+    fn from(c: Color3D) -> Vec3 {
+        Vec3::new(c.r, c.g, c.b)
+    }
+}
+
+impl From<Vec4> for Color4D {
+    fn from(v: Vec4) -> Color4D {
+        Color4D::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Color4D> for Vec4 {
+    fn from(c: Color4D) -> Vec4 {
+        Vec4::new(c.r, c.g, c.b, c.a)
+    }
+}
+
+impl From<Quat> for Quaternion {
+    fn from(q: Quat) -> Quaternion {
+        let [x, y, z, w] = q.to_array();
+        Quaternion::new(w, x, y, z)
+    }
+}
+
+impl From<Quaternion> for Quat {
+    fn from(q: Quaternion) -> Quat {
+        Quat::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+}
+
+// glam matrices are column-major, so the arrays of columns are the transpose of the rows
+impl From<Mat3> for Matrix3x3 {
+    fn from(m: Mat3) -> Matrix3x3 {
+        Matrix3x3::from_rows(m.transpose().to_cols_array_2d())
+    }
+}
+
+impl From<Matrix3x3> for Mat3 {
+    fn from(m: Matrix3x3) -> Mat3 {
+        Mat3::from_cols_array_2d(&m.rows()).transpose()
+    }
+}
+
+impl From<Mat4> for Matrix4x4 {
+    fn from(m: Mat4) -> Matrix4x4 {
+        Matrix4x4::from_rows(m.transpose().to_cols_array_2d())
+    }
+}
+
+impl From<Matrix4x4> for Mat4 {
+    fn from(m: Matrix4x4) -> Mat4 {
+        Mat4::from_cols_array_2d(&m.rows()).transpose()
+    }
+}
@@ -1,6 +1,10 @@
+use std::ops::Mul;
+
 use cgmath::Matrix3;
 use ffi::AiMatrix3x3;
 
+use super::{Matrix4x4, Vector3D};
+
 define_type! {
     /// Matrix3x3 docs
     #[derive(Clone, Copy, Debug, PartialEq)]
@@ -17,6 +21,93 @@ impl Matrix3x3 {
             c1: c2r0, c2: c2r1, c3: c2r2,
         })
     }
+
+    /// Returns the identity matrix.
+    pub fn identity() -> Matrix3x3 {
+        Matrix3x3::new(1.0, 0.0, 0.0,
+                       0.0, 1.0, 0.0,
+                       0.0, 0.0, 1.0)
+    }
+
+    /// Returns the transpose of the matrix.
+    pub fn transpose(self) -> Matrix3x3 {
+        Matrix3x3::new(self.a1, self.b1, self.c1,
+                       self.a2, self.b2, self.c2,
+                       self.a3, self.b3, self.c3)
+    }
+
+    /// Returns the determinant of the matrix.
+    pub fn determinant(self) -> f32 {
+        self.a1 * (self.b2 * self.c3 - self.b3 * self.c2) -
+        self.a2 * (self.b1 * self.c3 - self.b3 * self.c1) +
+        self.a3 * (self.b1 * self.c2 - self.b2 * self.c1)
+    }
+
+    /// Returns the inverse of the matrix, or `None` if the matrix isn't invertible.
+    pub fn inverse(self) -> Option<Matrix3x3> {
+        let det = self.determinant();
+        if det == 0.0 {
+            return None;
+        }
+        let inv = 1.0 / det;
+        Some(Matrix3x3::new((self.b2 * self.c3 - self.b3 * self.c2) * inv,
+                            (self.a3 * self.c2 - self.a2 * self.c3) * inv,
+                            (self.a2 * self.b3 - self.a3 * self.b2) * inv,
+                            (self.b3 * self.c1 - self.b1 * self.c3) * inv,
+                            (self.a1 * self.c3 - self.a3 * self.c1) * inv,
+                            (self.a3 * self.b1 - self.a1 * self.b3) * inv,
+                            (self.b1 * self.c2 - self.b2 * self.c1) * inv,
+                            (self.a2 * self.c1 - self.a1 * self.c2) * inv,
+                            (self.a1 * self.b2 - self.a2 * self.b1) * inv))
+    }
+
+    /// Transforms a vector by the matrix.
+    pub fn transform_vector(self, v: Vector3D) -> Vector3D {
+        Vector3D::new(self.a1 * v.x + self.a2 * v.y + self.a3 * v.z,
+                      self.b1 * v.x + self.b2 * v.y + self.b3 * v.z,
+                      self.c1 * v.x + self.c2 * v.y + self.c3 * v.z)
+    }
+
+    #[doc(hidden)]
+    pub fn from_rows(m: [[f32; 3]; 3]) -> Matrix3x3 {
+        Matrix3x3::new(m[0][0], m[0][1], m[0][2],
+                       m[1][0], m[1][1], m[1][2],
+                       m[2][0], m[2][1], m[2][2])
+    }
+
+    #[doc(hidden)]
+    pub fn rows(self) -> [[f32; 3]; 3] {
+        [[self.a1, self.a2, self.a3],
+         [self.b1, self.b2, self.b3],
+         [self.c1, self.c2, self.c3]]
+    }
+}
+
+/// Multiplies two matrices. Transforming by the result is the same as transforming by `rhs`
+/// followed by `self`.
+impl Mul for Matrix3x3 {
+    type Output = Matrix3x3;
+    fn mul(self, rhs: Matrix3x3) -> Matrix3x3 {
+        Matrix3x3::new(self.a1 * rhs.a1 + self.a2 * rhs.b1 + self.a3 * rhs.c1,
+                       self.a1 * rhs.a2 + self.a2 * rhs.b2 + self.a3 * rhs.c2,
+                       self.a1 * rhs.a3 + self.a2 * rhs.b3 + self.a3 * rhs.c3,
+                       self.b1 * rhs.a1 + self.b2 * rhs.b1 + self.b3 * rhs.c1,
+                       self.b1 * rhs.a2 + self.b2 * rhs.b2 + self.b3 * rhs.c2,
+                       self.b1 * rhs.a3 + self.b2 * rhs.b3 + self.b3 * rhs.c3,
+                       self.c1 * rhs.a1 + self.c2 * rhs.b1 + self.c3 * rhs.c1,
+                       self.c1 * rhs.a2 + self.c2 * rhs.b2 + self.c3 * rhs.c2,
+                       self.c1 * rhs.a3 + self.c2 * rhs.b3 + self.c3 * rhs.c3)
+    }
+}
+
+/// Takes the upper-left 3x3 part of the matrix, i.e. the rotation and scaling without the
+/// translation.
+impl From<Matrix4x4> for Matrix3x3 {
+    fn from(m: Matrix4x4) -> Matrix3x3 {
+        Matrix3x3::new(m.a1, m.a2, m.a3,
+                       m.b1, m.b2, m.b3,
+                       m.c1, m.c2, m.c3)
+    }
 }
 
 impl From<Matrix3<f32>> for Matrix3x3 {
@@ -27,6 +118,29 @@ impl From<Matrix3<f32>> for Matrix3x3 {
     }
 }
 
+// Serialized as an array of rows, matching the layout of Assimp's matrices
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for Matrix3x3 {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let rows = [[self.a1, self.a2, self.a3],
+                    [self.b1, self.b2, self.b3],
+                    [self.c1, self.c2, self.c3]];
+        ::serde::Serialize::serialize(&rows, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for Matrix3x3 {
+    fn deserialize<D>(deserializer: D) -> Result<Matrix3x3, D::Error>
+        where D: ::serde::Deserializer<'de>
+    {
+        let m: [[f32; 3]; 3] = try!(::serde::Deserialize::deserialize(deserializer));
+        Ok(Matrix3x3::new(m[0][0], m[0][1], m[0][2],
+                          m[1][0], m[1][1], m[1][2],
+                          m[2][0], m[2][1], m[2][2]))
+    }
+}
+
 impl Into<Matrix3<f32>> for Matrix3x3 {
     fn into(self) -> Matrix3<f32> {
         Matrix3::new(self.a1, self.b1, self.c1,
@@ -1,9 +1,14 @@
+use std::ops::Mul;
+
 use cgmath::Matrix4;
 use ffi::AiMatrix4x4;
 
+use super::{Matrix3x3, Quaternion, Vector3D};
+
 define_type! {
     /// Matrix4x4 docs
     #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
     struct Matrix4x4(AiMatrix4x4)
 }
 
@@ -19,6 +24,187 @@ impl Matrix4x4 {
             d1: c3r0, d2: c3r1, d3: c3r2, d4: c3r3,
         })
     }
+
+    /// Returns the identity matrix.
+    pub fn identity() -> Matrix4x4 {
+        Matrix4x4::from_rows([[1.0, 0.0, 0.0, 0.0],
+                              [0.0, 1.0, 0.0, 0.0],
+                              [0.0, 0.0, 1.0, 0.0],
+                              [0.0, 0.0, 0.0, 1.0]])
+    }
+
+    /// Creates a transformation which scales, then rotates, then translates, i.e. the inverse
+    /// of [`decompose`](#method.decompose).
+    pub fn compose(scaling: Vector3D, rotation: Quaternion, translation: Vector3D) -> Matrix4x4 {
+        let r = rotation.to_matrix();
+        Matrix4x4::from_rows([[r.a1 * scaling.x, r.a2 * scaling.y, r.a3 * scaling.z, translation.x],
+                              [r.b1 * scaling.x, r.b2 * scaling.y, r.b3 * scaling.z, translation.y],
+                              [r.c1 * scaling.x, r.c2 * scaling.y, r.c3 * scaling.z, translation.z],
+                              [0.0, 0.0, 0.0, 1.0]])
+    }
+
+    /// Decomposes a transformation into its scaling, rotation and translation, in the same way
+    /// as `aiDecomposeMatrix`.
+    ///
+    /// The matrix must not contain any shearing or projection. A transformation which mirrors
+    /// is decomposed into negative scaling on all axes.
+    pub fn decompose(self) -> (Vector3D, Quaternion, Vector3D) {
+        let translation = Vector3D::new(self.a4, self.b4, self.c4);
+
+        let mut columns = [Vector3D::new(self.a1, self.b1, self.c1),
+                           Vector3D::new(self.a2, self.b2, self.c2),
+                           Vector3D::new(self.a3, self.b3, self.c3)];
+        let mut scaling = Vector3D::new(columns[0].length(),
+                                        columns[1].length(),
+                                        columns[2].length());
+        if self.determinant() < 0.0 {
+            scaling = -scaling;
+        }
+
+        let scale = [scaling.x, scaling.y, scaling.z];
+        for (column, &scale) in columns.iter_mut().zip(scale.iter()) {
+            if scale != 0.0 {
+                *column = *column / scale;
+            }
+        }
+        let rotation = Matrix3x3::new(columns[0].x, columns[1].x, columns[2].x,
+                                      columns[0].y, columns[1].y, columns[2].y,
+                                      columns[0].z, columns[1].z, columns[2].z);
+        (scaling, Quaternion::from_rotation_matrix(rotation), translation)
+    }
+
+    /// Returns the transpose of the matrix.
+    pub fn transpose(self) -> Matrix4x4 {
+        let m = self.rows();
+        let mut t = [[0.0; 4]; 4];
+        for i in 0..4 {
+            for j in 0..4 {
+                t[i][j] = m[j][i];
+            }
+        }
+        Matrix4x4::from_rows(t)
+    }
+
+    /// Returns the determinant of the matrix.
+    pub fn determinant(self) -> f32 {
+        let (s, c) = self.minors();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    /// Returns the inverse of the matrix, or `None` if the matrix isn't invertible.
+    pub fn inverse(self) -> Option<Matrix4x4> {
+        let m = self.rows();
+        let (s, c) = self.minors();
+        let det = s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] +
+                  s[5] * c[0];
+        if det == 0.0 {
+            return None;
+        }
+
+        // The adjugate matrix, built from the 2x2 minors, divided by the determinant
+        let inv = 1.0 / det;
+        Some(Matrix4x4::from_rows([
+            [(m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3]) * inv,
+             (-m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3]) * inv,
+             (m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3]) * inv,
+             (-m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3]) * inv],
+            [(-m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1]) * inv,
+             (m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1]) * inv,
+             (-m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1]) * inv,
+             (m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1]) * inv],
+            [(m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0]) * inv,
+             (-m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0]) * inv,
+             (m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0]) * inv,
+             (-m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0]) * inv],
+            [(-m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0]) * inv,
+             (m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0]) * inv,
+             (-m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0]) * inv,
+             (m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0]) * inv]
+        ]))
+    }
+
+    /// Transforms a point by the matrix, i.e. including the translation.
+    pub fn transform_point(self, p: Vector3D) -> Vector3D {
+        Vector3D::new(self.a1 * p.x + self.a2 * p.y + self.a3 * p.z + self.a4,
+                      self.b1 * p.x + self.b2 * p.y + self.b3 * p.z + self.b4,
+                      self.c1 * p.x + self.c2 * p.y + self.c3 * p.z + self.c4)
+    }
+
+    /// Returns the matrix which transforms normals in the same way as this matrix transforms
+    /// positions, i.e. the inverse transpose of the upper-left 3x3 part.
+    ///
+    /// Normals transformed by the result need to be normalized again if the matrix scales. Returns
+    /// `None` if the matrix isn't invertible.
+    pub fn normal_matrix(self) -> Option<Matrix3x3> {
+        Matrix3x3::from(self).inverse().map(Matrix3x3::transpose)
+    }
+
+    /// Transforms a direction by the matrix, i.e. ignoring the translation.
+    pub fn transform_vector(self, v: Vector3D) -> Vector3D {
+        Vector3D::new(self.a1 * v.x + self.a2 * v.y + self.a3 * v.z,
+                      self.b1 * v.x + self.b2 * v.y + self.b3 * v.z,
+                      self.c1 * v.x + self.c2 * v.y + self.c3 * v.z)
+    }
+
+    #[doc(hidden)]
+    pub fn from_rows(m: [[f32; 4]; 4]) -> Matrix4x4 {
+        Matrix4x4::new(m[0][0], m[0][1], m[0][2], m[0][3],
+                       m[1][0], m[1][1], m[1][2], m[1][3],
+                       m[2][0], m[2][1], m[2][2], m[2][3],
+                       m[3][0], m[3][1], m[3][2], m[3][3])
+    }
+
+    #[doc(hidden)]
+    pub fn rows(self) -> [[f32; 4]; 4] {
+        [[self.a1, self.a2, self.a3, self.a4],
+         [self.b1, self.b2, self.b3, self.b4],
+         [self.c1, self.c2, self.c3, self.c4],
+         [self.d1, self.d2, self.d3, self.d4]]
+    }
+
+    // The 2x2 minors of the top two and bottom two rows, used by the determinant and inverse
+    fn minors(self) -> ([f32; 6], [f32; 6]) {
+        let m = self.rows();
+        let s = [m[0][0] * m[1][1] - m[1][0] * m[0][1],
+                 m[0][0] * m[1][2] - m[1][0] * m[0][2],
+                 m[0][0] * m[1][3] - m[1][0] * m[0][3],
+                 m[0][1] * m[1][2] - m[1][1] * m[0][2],
+                 m[0][1] * m[1][3] - m[1][1] * m[0][3],
+                 m[0][2] * m[1][3] - m[1][2] * m[0][3]];
+        let c = [m[2][0] * m[3][1] - m[3][0] * m[2][1],
+                 m[2][0] * m[3][2] - m[3][0] * m[2][2],
+                 m[2][0] * m[3][3] - m[3][0] * m[2][3],
+                 m[2][1] * m[3][2] - m[3][1] * m[2][2],
+                 m[2][1] * m[3][3] - m[3][1] * m[2][3],
+                 m[2][2] * m[3][3] - m[3][2] * m[2][3]];
+        (s, c)
+    }
+}
+
+/// Multiplies two matrices. Transforming by the result is the same as transforming by `rhs`
+/// followed by `self`.
+impl Mul for Matrix4x4 {
+    type Output = Matrix4x4;
+    fn mul(self, rhs: Matrix4x4) -> Matrix4x4 {
+        let (a, b) = (self.rows(), rhs.rows());
+        let mut m = [[0.0; 4]; 4];
+        for i in 0..4 {
+            for j in 0..4 {
+                m[i][j] = (0..4).map(|k| a[i][k] * b[k][j]).sum();
+            }
+        }
+        Matrix4x4::from_rows(m)
+    }
+}
+
+/// Creates a transformation with the matrix as its rotation and scaling, and no translation.
+impl From<Matrix3x3> for Matrix4x4 {
+    fn from(m: Matrix3x3) -> Matrix4x4 {
+        Matrix4x4::new(m.a1, m.a2, m.a3, 0.0,
+                       m.b1, m.b2, m.b3, 0.0,
+                       m.c1, m.c2, m.c3, 0.0,
+                       0.0, 0.0, 0.0, 1.0)
+    }
 }
 
 impl From<Matrix4<f32>> for Matrix4x4 {
@@ -30,6 +216,31 @@ impl From<Matrix4<f32>> for Matrix4x4 {
     }
 }
 
+// Serialized as an array of rows, matching the layout of Assimp's matrices
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for Matrix4x4 {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let rows = [[self.a1, self.a2, self.a3, self.a4],
+                    [self.b1, self.b2, self.b3, self.b4],
+                    [self.c1, self.c2, self.c3, self.c4],
+                    [self.d1, self.d2, self.d3, self.d4]];
+        ::serde::Serialize::serialize(&rows, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for Matrix4x4 {
+    fn deserialize<D>(deserializer: D) -> Result<Matrix4x4, D::Error>
+        where D: ::serde::Deserializer<'de>
+    {
+        let m: [[f32; 4]; 4] = try!(::serde::Deserialize::deserialize(deserializer));
+        Ok(Matrix4x4::new(m[0][0], m[0][1], m[0][2], m[0][3],
+                          m[1][0], m[1][1], m[1][2], m[1][3],
+                          m[2][0], m[2][1], m[2][2], m[2][3],
+                          m[3][0], m[3][1], m[3][2], m[3][3]))
+    }
+}
+
 impl Into<Matrix4<f32>> for Matrix4x4 {
     fn into(self) -> Matrix4<f32> {
         Matrix4::new(self.a1, self.b1, self.c1, self.d1,
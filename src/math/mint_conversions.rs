@@ -0,0 +1,139 @@
+//! Conversions to and from the `mint` types, enabled by the `mint` feature.
+//!
+//! Colors convert to vectors, as `mint` has no color types.
+
+use mint;
+
+use super::*;
+
+impl From<mint::Vector2<f32>> for Vector2D {
+    fn from(v: mint::Vector2<f32>) -> Vector2D {
+        Vector2D::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2D> for mint::Vector2<f32> {
+    fn from(v: Vector2D) -> mint::Vector2<f32> {
+        mint::Vector2 { x: v.x, y: v.y }
+    }
+}
+
+impl From<mint::Point2<f32>> for Vector2D {
+    fn from(p: mint::Point2<f32>) -> Vector2D {
+        Vector2D::new(p.x, p.y)
+    }
+}
+
+impl From<Vector2D> for mint::Point2<f32> {
+    fn from(v: Vector2D) -> mint::Point2<f32> {
+        mint::Point2 { x: v.x, y: v.y }
+    }
+}
+
+impl From<mint::Vector3<f32>> for Vector3D {
+    fn from(v: mint::Vector3<f32>) -> Vector3D {
+        Vector3D::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3D> for mint::Vector3<f32> {
+    fn from(v: Vector3D) -> mint::Vector3<f32> {
+        mint::Vector3 { x: v.x, y: v.y, z: v.z }
+    }
+}
+
+impl From<mint::Point3<f32>> for Vector3D {
+    fn from(p: mint::Point3<f32>) -> Vector3D {
+        Vector3D::new(p.x, p.y, p.z)
+    }
+}
+
+impl From<Vector3D> for mint::Point3<f32> {
+    fn from(v: Vector3D) -> mint::Point3<f32> {
+        mint::Point3 { x: v.x, y: v.y, z: v.z }
+    }
+}
+
+impl From<mint::Vector3<f32>> for Color3D {
+    fn from(v: mint::Vector3<f32>) -> Color3D {
+        Color3D::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Color3D> for mint::Vector3<f32> {
+    fn from(c: Color3D) -> mint::Vector3<f32> {
+        mint::Vector3 { x: c.r, y: c.g, z: c.b }
+    }
+}
+
+impl From<mint::Vector4<f32>> for Color4D {
+    fn from(v: mint::Vector4<f32>) -> Color4D {
+        Color4D::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Color4D> for mint::Vector4<f32> {
+    fn from(c: Color4D) -> mint::Vector4<f32> {
+        mint::Vector4 { x: c.r, y: c.g, z: c.b, w: c.a }
+    }
+}
+
+impl From<mint::Quaternion<f32>> for Quaternion {
+    fn from(q: mint::Quaternion<f32>) -> Quaternion {
+        Quaternion::new(q.s, q.v.x, q.v.y, q.v.z)
+    }
+}
+
+impl From<Quaternion> for mint::Quaternion<f32> {
+    fn from(q: Quaternion) -> mint::Quaternion<f32> {
+        mint::Quaternion { v: mint::Vector3 { x: q.x, y: q.y, z: q.z }, s: q.w }
+    }
+}
+
+impl From<mint::RowMatrix3<f32>> for Matrix3x3 {
+    fn from(m: mint::RowMatrix3<f32>) -> Matrix3x3 {
+        Matrix3x3::from_rows(m.into())
+    }
+}
+
+impl From<Matrix3x3> for mint::RowMatrix3<f32> {
+    fn from(m: Matrix3x3) -> mint::RowMatrix3<f32> {
+        m.rows().into()
+    }
+}
+
+impl From<mint::ColumnMatrix3<f32>> for Matrix3x3 {
+    fn from(m: mint::ColumnMatrix3<f32>) -> Matrix3x3 {
+        mint::RowMatrix3::from(m).into()
+    }
+}
+
+impl From<Matrix3x3> for mint::ColumnMatrix3<f32> {
+    fn from(m: Matrix3x3) -> mint::ColumnMatrix3<f32> {
+        mint::RowMatrix3::from(m).into()
+    }
+}
+
+impl From<mint::RowMatrix4<f32>> for Matrix4x4 {
+    fn from(m: mint::RowMatrix4<f32>) -> Matrix4x4 {
+        Matrix4x4::from_rows(m.into())
+    }
+}
+
+impl From<Matrix4x4> for mint::RowMatrix4<f32> {
+    fn from(m: Matrix4x4) -> mint::RowMatrix4<f32> {
+        m.rows().into()
+    }
+}
+
+impl From<mint::ColumnMatrix4<f32>> for Matrix4x4 {
+    fn from(m: mint::ColumnMatrix4<f32>) -> Matrix4x4 {
+        mint::RowMatrix4::from(m).into()
+    }
+}
+
+impl From<Matrix4x4> for mint::ColumnMatrix4<f32> {
+    fn from(m: Matrix4x4) -> mint::ColumnMatrix4<f32> {
+        mint::RowMatrix4::from(m).into()
+    }
+}
@@ -1,8 +1,17 @@
 //! The `math` module contains definitions of primitive math types.
 //!
-//! Not really anything useful here. Conversion traits are implemented on each type to convert
-//! into/from the much more useful `cgmath` types.
+//! The types implement the basic operations needed to work with scene data, e.g. vector
+//! arithmetic, matrix multiplication and inversion, and decomposing a node's transformation
+//! into scaling, rotation and translation. Matrices follow Assimp's conventions: they are
+//! row-major, and points are transformed as column vectors.
+//!
+//! Conversion traits are implemented on each type to convert into/from the much more complete
+//! `cgmath` types.
 //! e.g. `Matrix3x3` converts to/from `cgmath::Matrix3<f32>`.
+//!
+//! The `glam`, `mint` and `nalgebra` features add `From` conversions to and from the equivalent
+//! types of those crates. Matrices are converted so they represent the same transformation,
+//! regardless of how each crate stores them.
 
 pub use self::color3::Color3D;
 pub use self::color4::Color4D;
@@ -19,3 +28,10 @@ pub mod matrix4;
 pub mod quaternion;
 pub mod vector2;
 pub mod vector3;
+
+#[cfg(feature = "glam")]
+mod glam_conversions;
+#[cfg(feature = "mint")]
+mod mint_conversions;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_conversions;
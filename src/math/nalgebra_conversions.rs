@@ -0,0 +1,139 @@
+//! Conversions to and from the `nalgebra` types, enabled by the `nalgebra` feature.
+//!
+//! Colors convert to vectors, as `nalgebra` has no color types.
+
+use nalgebra::{Matrix3, Matrix4, Point2, Point3, UnitQuaternion, Vector2, Vector3, Vector4};
+use nalgebra::Quaternion as NaQuaternion;
+
+use super::*;
+
+impl From<Vector2<f32>> for Vector2D {
+    fn from(v: Vector2<f32>) -> Vector2D {
+        Vector2D::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2D> for Vector2<f32> {
+    fn from(v: Vector2D) -> Vector2<f32> {
+        Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<Point2<f32>> for Vector2D {
+    fn from(p: Point2<f32>) -> Vector2D {
+        Vector2D::new(p.x, p.y)
+    }
+}
+
+impl From<Vector2D> for Point2<f32> {
+    fn from(v: Vector2D) -> Point2<f32> {
+        Point2::new(v.x, v.y)
+    }
+}
+
+impl From<Vector3<f32>> for Vector3D {
+    fn from(v: Vector3<f32>) -> Vector3D {
+        Vector3D::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3D> for Vector3<f32> {
+    fn from(v: Vector3D) -> Vector3<f32> {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Point3<f32>> for Vector3D {
+    fn from(p: Point3<f32>) -> Vector3D {
+        Vector3D::new(p.x, p.y, p.z)
+    }
+}
+
+impl From<Vector3D> for Point3<f32> {
+    fn from(v: Vector3D) -> Point3<f32> {
+        Point3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3<f32>> for Color3D {
+    fn from(v: Vector3<f32>) -> Color3D {
+        Color3D::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Color3D> for Vector3<f32> {
+    fn from(c: Color3D) -> Vector3<f32> {
+        Vector3::new(c.r, c.g, c.b)
+    }
+}
+
+impl From<Vector4<f32>> for Color4D {
+    fn from(v: Vector4<f32>) -> Color4D {
+        Color4D::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Color4D> for Vector4<f32> {
+    fn from(c: Color4D) -> Vector4<f32> {
+        Vector4::new(c.r, c.g, c.b, c.a)
+    }
+}
+
+impl From<NaQuaternion<f32>> for Quaternion {
+    fn from(q: NaQuaternion<f32>) -> Quaternion {
+        Quaternion::new(q.w, q.i, q.j, q.k)
+    }
+}
+
+impl From<Quaternion> for NaQuaternion<f32> {
+    fn from(q: Quaternion) -> NaQuaternion<f32> {
+        NaQuaternion::new(q.w, q.x, q.y, q.z)
+    }
+}
+
+impl From<UnitQuaternion<f32>> for Quaternion {
+    fn from(q: UnitQuaternion<f32>) -> Quaternion {
+        q.into_inner().into()
+    }
+}
+
+/// The quaternion is normalized, as Assimp doesn't guarantee rotations are unit quaternions.
+impl From<Quaternion> for UnitQuaternion<f32> {
+    fn from(q: Quaternion) -> UnitQuaternion<f32> {
+        UnitQuaternion::new_normalize(q.into())
+    }
+}
+
+impl From<Matrix3<f32>> for Matrix3x3 {
+    fn from(m: Matrix3<f32>) -> Matrix3x3 {
+        Matrix3x3::new(m[(0, 0)], m[(0, 1)], m[(0, 2)],
+                       m[(1, 0)], m[(1, 1)], m[(1, 2)],
+                       m[(2, 0)], m[(2, 1)], m[(2, 2)])
+    }
+}
+
+impl From<Matrix3x3> for Matrix3<f32> {
+    fn from(m: Matrix3x3) -> Matrix3<f32> {
+        Matrix3::new(m.a1, m.a2, m.a3,
+                     m.b1, m.b2, m.b3,
+                     m.c1, m.c2, m.c3)
+    }
+}
+
+impl From<Matrix4<f32>> for Matrix4x4 {
+    fn from(m: Matrix4<f32>) -> Matrix4x4 {
+        Matrix4x4::new(m[(0, 0)], m[(0, 1)], m[(0, 2)], m[(0, 3)],
+                       m[(1, 0)], m[(1, 1)], m[(1, 2)], m[(1, 3)],
+                       m[(2, 0)], m[(2, 1)], m[(2, 2)], m[(2, 3)],
+                       m[(3, 0)], m[(3, 1)], m[(3, 2)], m[(3, 3)])
+    }
+}
+
+impl From<Matrix4x4> for Matrix4<f32> {
+    fn from(m: Matrix4x4) -> Matrix4<f32> {
+        Matrix4::new(m.a1, m.a2, m.a3, m.a4,
+                     m.b1, m.b2, m.b3, m.b4,
+                     m.c1, m.c2, m.c3, m.c4,
+                     m.d1, m.d2, m.d3, m.d4)
+    }
+}
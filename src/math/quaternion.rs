@@ -1,9 +1,14 @@
+use std::ops::Mul;
+
 use cgmath::Quaternion as CgQuaternion;
 use ffi::AiQuaternion;
 
+use super::{Matrix3x3, Vector3D};
+
 define_type! {
     /// Quaternion docs
     #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
     struct Quaternion(AiQuaternion)
 }
 
@@ -11,8 +16,134 @@ impl Quaternion {
     pub fn new(w: f32, x: f32, y: f32 ,z: f32) -> Quaternion {
         Quaternion(AiQuaternion { w: w, x: x, y: y, z: z })
     }
+
+    /// Returns the quaternion representing no rotation.
+    pub fn identity() -> Quaternion {
+        Quaternion::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Creates a quaternion rotating by `angle` radians around `axis`.
+    pub fn from_axis_angle(axis: Vector3D, angle: f32) -> Quaternion {
+        let axis = axis.normalize();
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        Quaternion::new(cos, axis.x * sin, axis.y * sin, axis.z * sin)
+    }
+
+    /// Creates a quaternion from a rotation matrix.
+    pub fn from_rotation_matrix(m: Matrix3x3) -> Quaternion {
+        // Same method as the aiQuaternion constructor, choosing the largest diagonal element
+        // for numerical stability
+        let t = m.a1 + m.b2 + m.c3;
+        if t > 0.0 {
+            let s = (1.0 + t).sqrt() * 2.0;
+            Quaternion::new(0.25 * s, (m.c2 - m.b3) / s, (m.a3 - m.c1) / s, (m.b1 - m.a2) / s)
+        } else if m.a1 > m.b2 && m.a1 > m.c3 {
+            let s = (1.0 + m.a1 - m.b2 - m.c3).sqrt() * 2.0;
+            Quaternion::new((m.c2 - m.b3) / s, 0.25 * s, (m.a2 + m.b1) / s, (m.c1 + m.a3) / s)
+        } else if m.b2 > m.c3 {
+            let s = (1.0 + m.b2 - m.a1 - m.c3).sqrt() * 2.0;
+            Quaternion::new((m.a3 - m.c1) / s, (m.a2 + m.b1) / s, 0.25 * s, (m.b3 + m.c2) / s)
+        } else {
+            let s = (1.0 + m.c3 - m.a1 - m.b2).sqrt() * 2.0;
+            Quaternion::new((m.b1 - m.a2) / s, (m.c1 + m.a3) / s, (m.b3 + m.c2) / s, 0.25 * s)
+        }
+    }
+
+    /// Returns the rotation matrix equivalent to this quaternion, which must be normalized.
+    pub fn to_matrix(self) -> Matrix3x3 {
+        let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+        Matrix3x3::new(1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - z * w), 2.0 * (x * z + y * w),
+                       2.0 * (x * y + z * w), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - x * w),
+                       2.0 * (x * z - y * w), 2.0 * (y * z + x * w), 1.0 - 2.0 * (x * x + y * y))
+    }
+
+    /// Returns the dot product of two quaternions.
+    pub fn dot(self, rhs: Quaternion) -> f32 {
+        self.w * rhs.w + self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    /// Returns the length of the quaternion.
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns the quaternion scaled to a length of 1, or unchanged if its length is zero.
+    pub fn normalize(self) -> Quaternion {
+        let length = self.length();
+        if length == 0.0 {
+            self
+        } else {
+            Quaternion::new(self.w / length, self.x / length, self.y / length, self.z / length)
+        }
+    }
+
+    /// Returns the conjugate of the quaternion, which is the inverse rotation if the quaternion
+    /// is normalized.
+    pub fn conjugate(self) -> Quaternion {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// Rotates a vector by this quaternion, which must be normalized.
+    pub fn rotate(self, v: Vector3D) -> Vector3D {
+        let q = self * Quaternion::new(0.0, v.x, v.y, v.z) * self.conjugate();
+        Vector3D::new(q.x, q.y, q.z)
+    }
+
+    /// Spherical linear interpolation between two rotations, where `factor` is between 0 (this
+    /// rotation) and 1 (`end`).
+    ///
+    /// Interpolates along the shortest path, in the same way as Assimp's
+    /// `aiQuaternion::Interpolate`.
+    pub fn slerp(self, end: Quaternion, factor: f32) -> Quaternion {
+        let mut cos = self.dot(end);
+        let mut end = end;
+        if cos < 0.0 {
+            cos = -cos;
+            end = Quaternion::new(-end.w, -end.x, -end.y, -end.z);
+        }
+
+        // Fall back to linear interpolation if the rotations are very close
+        let (scale_start, scale_end) = if 1.0 - cos > 0.0001 {
+            let omega = cos.acos();
+            let sin = omega.sin();
+            (((1.0 - factor) * omega).sin() / sin, (factor * omega).sin() / sin)
+        } else {
+            (1.0 - factor, factor)
+        };
+        Quaternion::new(scale_start * self.w + scale_end * end.w,
+                        scale_start * self.x + scale_end * end.x,
+                        scale_start * self.y + scale_end * end.y,
+                        scale_start * self.z + scale_end * end.z)
+    }
+}
+
+/// Combines two rotations, the result applies `rhs` followed by `self`.
+impl Mul for Quaternion {
+    type Output = Quaternion;
+    fn mul(self, rhs: Quaternion) -> Quaternion {
+        Quaternion::new(self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+                        self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+                        self.w * rhs.y + self.y * rhs.w + self.z * rhs.x - self.x * rhs.z,
+                        self.w * rhs.z + self.z * rhs.w + self.x * rhs.y - self.y * rhs.x)
+    }
 }
 
+impl From<[f32; 4]> for Quaternion {
+    fn from(q: [f32; 4]) -> Quaternion {
+        Quaternion::new(q[0], q[1], q[2], q[3])
+    }
+}
+
+impl Into<[f32; 4]> for Quaternion {
+    fn into(self) -> [f32; 4] {
+        [self.w, self.x, self.y, self.z]
+    }
+}
+
+// Serialized in Assimp's order, i.e. `[w, x, y, z]`
+#[cfg(feature = "serde")]
+impl_serde_array!(Quaternion, [f32; 4]);
+
 impl From<CgQuaternion<f32>> for Quaternion {
     fn from(q: CgQuaternion<f32>) -> Quaternion {
         Quaternion::new(q[0], q[1], q[2], q[3])
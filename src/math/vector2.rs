@@ -1,5 +1,7 @@
+use std::ops::Neg;
+
 use cgmath::{Point2, Vector2};
-use ffi::AiVector2D;
+use ffi::{AiVector2D, AiVector3D};
 
 define_type! {
     /// Vector2D docs
@@ -7,10 +9,62 @@ define_type! {
     struct Vector2D(AiVector2D)
 }
 
+define_iter! {
+    /// Iterator over 2D vectors stored in an array of `AiVector3D`, e.g. the texture coordinates
+    /// of a mesh. The third component of each vector is ignored.
+    struct Vector2DIter -> *const AiVector3D
+}
+
+impl<'a> Iterator for Vector2DIter<'a> {
+    type Item = Vector2D;
+    fn next(&mut self) -> Option<Vector2D> {
+        if self.idx < self.len as isize {
+            let v = unsafe { *self.ptr.offset(self.idx) };
+            self.idx = self.idx + 1;
+            Some(Vector2D::new(v.x, v.y))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len - self.idx as usize;
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for Vector2DIter<'a> {}
+
 impl Vector2D {
     pub fn new(x: f32, y: f32) -> Vector2D {
         Vector2D(AiVector2D { x: x, y: y })
     }
+
+    /// Returns the dot product of two vectors.
+    pub fn dot(self, rhs: Vector2D) -> f32 {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    /// Returns the length of the vector.
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns a vector with the same direction and a length of 1, or the vector unchanged if
+    /// its length is zero.
+    pub fn normalize(self) -> Vector2D {
+        let length = self.length();
+        if length == 0.0 { self } else { self / length }
+    }
+}
+
+impl_component_ops!(Vector2D { x, y });
+
+impl Neg for Vector2D {
+    type Output = Vector2D;
+    fn neg(self) -> Vector2D {
+        Vector2D::new(-self.x, -self.y)
+    }
 }
 
 impl From<[f32; 2]> for Vector2D {
@@ -25,6 +79,9 @@ impl Into<[f32; 2]> for Vector2D {
     }
 }
 
+#[cfg(feature = "serde")]
+impl_serde_array!(Vector2D, [f32; 2]);
+
 impl From<Point2<f32>> for Vector2D {
     fn from(p: Point2<f32>) -> Vector2D {
         Vector2D::new(p[0], p[1])
@@ -1,9 +1,12 @@
+use std::ops::Neg;
+
 use cgmath::{Point3, Vector3};
 use ffi::AiVector3D;
 
 define_type_and_iterator! {
     /// Vector3D docs
     #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
     struct Vector3D(AiVector3D)
     /// Vector3DIter docs
     struct Vector3DIter
@@ -13,6 +16,39 @@ impl Vector3D {
     pub fn new(x: f32, y: f32, z: f32) -> Vector3D {
         Vector3D(AiVector3D { x: x, y: y, z: z })
     }
+
+    /// Returns the dot product of two vectors.
+    pub fn dot(self, rhs: Vector3D) -> f32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    /// Returns the cross product of two vectors.
+    pub fn cross(self, rhs: Vector3D) -> Vector3D {
+        Vector3D::new(self.y * rhs.z - self.z * rhs.y,
+                      self.z * rhs.x - self.x * rhs.z,
+                      self.x * rhs.y - self.y * rhs.x)
+    }
+
+    /// Returns the length of the vector.
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns a vector with the same direction and a length of 1, or the vector unchanged if
+    /// its length is zero.
+    pub fn normalize(self) -> Vector3D {
+        let length = self.length();
+        if length == 0.0 { self } else { self / length }
+    }
+}
+
+impl_component_ops!(Vector3D { x, y, z });
+
+impl Neg for Vector3D {
+    type Output = Vector3D;
+    fn neg(self) -> Vector3D {
+        Vector3D::new(-self.x, -self.y, -self.z)
+    }
 }
 
 impl From<[f32; 3]> for Vector3D {
@@ -27,6 +63,9 @@ impl Into<[f32; 3]> for Vector3D {
     }
 }
 
+#[cfg(feature = "serde")]
+impl_serde_array!(Vector3D, [f32; 3]);
+
 impl From<Point3<f32>> for Vector3D {
     fn from(p: Point3<f32>) -> Vector3D {
         Vector3D::new(p[0], p[1], p[2])
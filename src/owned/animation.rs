@@ -0,0 +1,151 @@
+use ffi::{AiAnimBehaviour, AiMeshAnim, AiNodeAnim, AiVectorKey};
+
+use math::{Quaternion, Vector3D};
+use scene::Animation;
+
+use super::raw_slice;
+
+/// An owned copy of an `Animation`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedAnimation {
+    pub name: String,
+    /// Duration of the animation in ticks.
+    pub duration: f64,
+    /// Ticks per second, 0 if not specified in the imported file.
+    pub ticks_per_second: f64,
+    /// Node animation channels, each affecting a single node.
+    pub channels: Vec<OwnedNodeAnim>,
+    /// Mesh animation channels, each affecting a single mesh.
+    pub mesh_channels: Vec<OwnedMeshAnim>
+}
+
+/// The animation of a single node.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedNodeAnim {
+    /// The name of the node affected by this animation.
+    pub node_name: String,
+    pub position_keys: Vec<OwnedVectorKey>,
+    pub rotation_keys: Vec<OwnedQuatKey>,
+    pub scaling_keys: Vec<OwnedVectorKey>,
+    /// How the animation behaves before the first key.
+    pub pre_state: AnimBehaviour,
+    /// How the animation behaves after the last key.
+    pub post_state: AnimBehaviour
+}
+
+/// The animation of a single mesh, which switches between its `anim_meshes`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedMeshAnim {
+    /// The name of the mesh affected by this animation.
+    pub name: String,
+    pub keys: Vec<OwnedMeshKey>
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct OwnedVectorKey {
+    pub time: f64,
+    pub value: Vector3D
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct OwnedQuatKey {
+    pub time: f64,
+    pub value: Quaternion
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct OwnedMeshKey {
+    pub time: f64,
+    /// Index into `OwnedMesh::anim_meshes`.
+    pub value: u32
+}
+
+/// Defines how an animation channel behaves outside the defined time range.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AnimBehaviour {
+    /// The value from the default node transformation is used.
+    Default,
+    /// The nearest key value is used without interpolation.
+    Constant,
+    /// The value of the nearest two keys is linearly extrapolated.
+    Linear,
+    /// The animation is repeated.
+    Repeat
+}
+
+impl From<AiAnimBehaviour> for AnimBehaviour {
+    fn from(behaviour: AiAnimBehaviour) -> AnimBehaviour {
+        match behaviour {
+            AiAnimBehaviour::Default => AnimBehaviour::Default,
+            AiAnimBehaviour::Constant => AnimBehaviour::Constant,
+            AiAnimBehaviour::Linear => AnimBehaviour::Linear,
+            AiAnimBehaviour::Repeat => AnimBehaviour::Repeat
+        }
+    }
+}
+
+impl<'a, 'b> From<&'b Animation<'a>> for OwnedAnimation {
+    fn from(animation: &'b Animation<'a>) -> OwnedAnimation {
+        unsafe {
+            OwnedAnimation {
+                name: super::to_string(&animation.name),
+                duration: animation.duration,
+                ticks_per_second: animation.ticks_per_second,
+                channels: raw_slice(animation.channels, animation.num_channels as usize)
+                    .iter()
+                    .map(|&x| node_anim(&*x))
+                    .collect(),
+                mesh_channels: raw_slice(animation.mesh_channels,
+                                         animation.num_mesh_channels as usize)
+                    .iter()
+                    .map(|&x| mesh_anim(&*x))
+                    .collect()
+            }
+        }
+    }
+}
+
+impl<'a> From<Animation<'a>> for OwnedAnimation {
+    fn from(animation: Animation<'a>) -> OwnedAnimation {
+        OwnedAnimation::from(&animation)
+    }
+}
+
+unsafe fn node_anim(node_anim: &AiNodeAnim) -> OwnedNodeAnim {
+    OwnedNodeAnim {
+        node_name: super::to_string(&node_anim.node_name),
+        position_keys: vector_keys(node_anim.position_keys, node_anim.num_position_keys),
+        rotation_keys: raw_slice(node_anim.rotation_keys, node_anim.num_rotation_keys as usize)
+            .iter()
+            .map(|x| OwnedQuatKey { time: x.time, value: Quaternion::from_raw(&x.velue) })
+            .collect(),
+        scaling_keys: vector_keys(node_anim.scaling_keys, node_anim.num_scaling_keys),
+        pre_state: From::from(node_anim.pre_state),
+        post_state: From::from(node_anim.post_state)
+    }
+}
+
+unsafe fn vector_keys(keys: *const AiVectorKey, len: u32) -> Vec<OwnedVectorKey> {
+    raw_slice(keys, len as usize).iter().map(|x| {
+        OwnedVectorKey { time: x.time, value: Vector3D::from_raw(&x.value) }
+    }).collect()
+}
+
+unsafe fn mesh_anim(mesh_anim: &AiMeshAnim) -> OwnedMeshAnim {
+    OwnedMeshAnim {
+        name: super::to_string(&mesh_anim.name),
+        keys: raw_slice(mesh_anim.keys, mesh_anim.num_keys as usize).iter().map(|x| {
+            OwnedMeshKey { time: x.time, value: x.value }
+        }).collect()
+    }
+}
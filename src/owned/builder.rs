@@ -0,0 +1,117 @@
+use scene::Scene;
+
+use super::*;
+
+/// Builds a `Scene` from Rust data, e.g. to export procedurally generated geometry.
+///
+/// Items are added to the scene with the `add_*` methods, which return the index other items
+/// use to refer to them. The data is validated and copied into a new `Scene` by `build`.
+///
+/// # Examples
+/// ```
+/// use assimp::Vector3D;
+/// use assimp::owned::{OwnedMaterial, OwnedMesh, OwnedNode, SceneBuilder};
+///
+/// let mut builder = SceneBuilder::new();
+/// let material = builder.add_material(OwnedMaterial::new("red"));
+///
+/// let vertices = vec![Vector3D::new(0.0, 0.0, 0.0),
+///                     Vector3D::new(1.0, 0.0, 0.0),
+///                     Vector3D::new(0.0, 1.0, 0.0)];
+/// let mut mesh = OwnedMesh::new("triangle", vertices, vec![vec![0, 1, 2]]);
+/// mesh.material_index = material;
+///
+/// let mut node = OwnedNode::new("triangle");
+/// node.meshes.push(builder.add_mesh(mesh));
+/// builder.add_node(node);
+///
+/// let scene = builder.build().unwrap();
+/// assert_eq!(scene.num_meshes(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SceneBuilder {
+    scene: OwnedScene
+}
+
+impl SceneBuilder {
+    /// Create a builder for an empty scene with a root node named "root".
+    pub fn new() -> SceneBuilder {
+        SceneBuilder {
+            scene: OwnedScene {
+                flags: 0,
+                root_node: OwnedNode::new("root"),
+                meshes: Vec::new(),
+                materials: Vec::new(),
+                animations: Vec::new(),
+                textures: Vec::new(),
+                lights: Vec::new(),
+                cameras: Vec::new()
+            }
+        }
+    }
+
+    /// Adds a mesh, returning its index. The mesh isn't part of the scene hierarchy until a node
+    /// refers to it.
+    pub fn add_mesh(&mut self, mesh: OwnedMesh) -> u32 {
+        push(&mut self.scene.meshes, mesh)
+    }
+
+    /// Adds a material, returning its index.
+    pub fn add_material(&mut self, material: OwnedMaterial) -> u32 {
+        push(&mut self.scene.materials, material)
+    }
+
+    /// Adds an animation, returning its index.
+    pub fn add_animation(&mut self, animation: OwnedAnimation) -> u32 {
+        push(&mut self.scene.animations, animation)
+    }
+
+    /// Adds an embedded texture, returning its index.
+    ///
+    /// Materials refer to embedded textures by a path of `*` followed by the index, e.g. `"*0"`.
+    pub fn add_texture(&mut self, texture: OwnedTexture) -> u32 {
+        push(&mut self.scene.textures, texture)
+    }
+
+    /// Adds a light, returning its index. The light is positioned by the node with the same name.
+    pub fn add_light(&mut self, light: OwnedLight) -> u32 {
+        push(&mut self.scene.lights, light)
+    }
+
+    /// Adds a camera, returning its index. The camera is positioned by the node with the same
+    /// name.
+    pub fn add_camera(&mut self, camera: OwnedCamera) -> u32 {
+        push(&mut self.scene.cameras, camera)
+    }
+
+    /// Adds a node as a child of the root node.
+    pub fn add_node(&mut self, node: OwnedNode) {
+        self.scene.root_node.children.push(node);
+    }
+
+    /// Returns the root node of the scene hierarchy, e.g. to add nodes at a deeper level.
+    pub fn root_node(&mut self) -> &mut OwnedNode {
+        &mut self.scene.root_node
+    }
+
+    /// Validates the scene and copies it into a new `Scene`, see `OwnedScene::to_scene`.
+    pub fn build(&self) -> Result<Scene, BuildError> {
+        self.scene.to_scene()
+    }
+
+    /// Returns the scene data without building a `Scene`.
+    pub fn into_owned(self) -> OwnedScene {
+        self.scene
+    }
+}
+
+impl Default for SceneBuilder {
+    fn default() -> SceneBuilder {
+        SceneBuilder::new()
+    }
+}
+
+fn push<T>(items: &mut Vec<T>, item: T) -> u32 {
+    items.push(item);
+    items.len() as u32 - 1
+}
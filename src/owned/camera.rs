@@ -0,0 +1,43 @@
+use math::Vector3D;
+use scene::Camera;
+
+/// An owned copy of a `Camera`.
+///
+/// The position and orientation are relative to the node with the same name as the camera.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedCamera {
+    pub name: String,
+    pub position: Vector3D,
+    pub up: Vector3D,
+    pub look_at: Vector3D,
+    /// Half horizontal field of view angle, in radians.
+    pub horizontal_fov: f32,
+    pub clip_plane_near: f32,
+    pub clip_plane_far: f32,
+    /// Screen aspect ratio, 0 if not specified in the imported file.
+    pub aspect: f32
+}
+
+impl<'a, 'b> From<&'b Camera<'a>> for OwnedCamera {
+    fn from(camera: &'b Camera<'a>) -> OwnedCamera {
+        unsafe {
+            OwnedCamera {
+                name: super::to_string(&camera.name),
+                position: Vector3D::from_raw(&camera.position),
+                up: Vector3D::from_raw(&camera.up),
+                look_at: Vector3D::from_raw(&camera.look_at),
+                horizontal_fov: camera.horizontal_fov,
+                clip_plane_near: camera.clip_plane_near,
+                clip_plane_far: camera.clip_plane_far,
+                aspect: camera.aspect
+            }
+        }
+    }
+}
+
+impl<'a> From<Camera<'a>> for OwnedCamera {
+    fn from(camera: Camera<'a>) -> OwnedCamera {
+        OwnedCamera::from(&camera)
+    }
+}
@@ -0,0 +1,175 @@
+use super::*;
+use super::error::ValidationError;
+use super::error::ValidationError::*;
+
+/// Editing operations which keep the scene consistent.
+///
+/// Nodes are identified by name. Assimp doesn't require names to be unique, so where several
+/// nodes have the same name the first one found in a depth-first search is used. Each edit
+/// validates the scene once it's made, see [`validate`](#method.validate), and edits which
+/// would make the scene inconsistent fail without changing it.
+impl OwnedScene {
+    /// Returns the node with the given name.
+    pub fn find_node(&self, name: &str) -> Option<&OwnedNode> {
+        find_node(&self.root_node, name)
+    }
+
+    /// Returns the node with the given name.
+    pub fn find_node_mut(&mut self, name: &str) -> Option<&mut OwnedNode> {
+        find_node_mut(&mut self.root_node, name)
+    }
+
+    /// Renames a node, along with the bones, animation channels, lights and cameras which refer
+    /// to it by name.
+    ///
+    /// Fails if there isn't exactly one node called `name`, or another node is already called
+    /// `new_name`.
+    pub fn rename_node(&mut self, name: &str, new_name: &str) -> Result<(), ValidationError> {
+        match count_nodes(&self.root_node, name) {
+            0 => return Err(MissingNode { name: name.to_owned() }),
+            1 => {}
+            _ => return Err(DuplicateNode { name: name.to_owned() })
+        }
+        if new_name != name && count_nodes(&self.root_node, new_name) > 0 {
+            return Err(DuplicateNode { name: new_name.to_owned() });
+        }
+
+        self.find_node_mut(name).unwrap().name = new_name.to_owned();
+        let rename = |x: &mut String| if *x == name { *x = new_name.to_owned() };
+        for bone in self.meshes.iter_mut().flat_map(|x| x.bones.iter_mut()) {
+            rename(&mut bone.name);
+        }
+        for channel in self.animations.iter_mut().flat_map(|x| x.channels.iter_mut()) {
+            rename(&mut channel.node_name);
+        }
+        for light in &mut self.lights {
+            rename(&mut light.name);
+        }
+        for camera in &mut self.cameras {
+            rename(&mut camera.name);
+        }
+        self.validate()
+    }
+
+    /// Moves a node, with all of its children, to become the last child of `new_parent`.
+    ///
+    /// The node's transformation is relative to its parent, so it isn't changed by this method.
+    ///
+    /// Fails if either node doesn't exist, if `name` is the root node, or if `new_parent` is
+    /// `name` or one of its descendants.
+    pub fn reparent_node(&mut self, name: &str, new_parent: &str)
+                         -> Result<(), ValidationError> {
+        let valid = match self.find_node(name) {
+            Some(node) => find_node(node, new_parent).is_none() && self.root_node.name != name,
+            None => return Err(MissingNode { name: name.to_owned() })
+        };
+        if self.find_node(new_parent).is_none() {
+            return Err(MissingNode { name: new_parent.to_owned() });
+        }
+        if !valid {
+            return Err(InvalidParent { node: name.to_owned(), parent: new_parent.to_owned() });
+        }
+
+        let node = take_node(&mut self.root_node, name).unwrap();
+        self.find_node_mut(new_parent).unwrap().children.push(node);
+        self.validate()
+    }
+
+    /// Removes a mesh from the scene, updating the mesh indices of all nodes.
+    ///
+    /// Mesh animation channels refer to meshes by name, so channels animating the removed mesh
+    /// are removed too, unless another mesh has the same name.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn remove_mesh(&mut self, index: u32) -> Result<OwnedMesh, ValidationError> {
+        let mesh = self.meshes.remove(index as usize);
+        remove_mesh_index(&mut self.root_node, index);
+        if self.meshes.iter().all(|x| x.name != mesh.name) {
+            for animation in &mut self.animations {
+                animation.mesh_channels.retain(|x| x.name != mesh.name);
+            }
+        }
+        try!(self.validate());
+        Ok(mesh)
+    }
+
+    /// Changes all meshes using the material `from` to use the material `to`.
+    ///
+    /// Fails if a mesh uses `from` and the material `to` doesn't exist.
+    pub fn reassign_material(&mut self, from: u32, to: u32) -> Result<(), ValidationError> {
+        if to as usize >= self.materials.len() {
+            if let Some(mesh) = self.meshes.iter().position(|x| x.material_index == from) {
+                return Err(MaterialIndexOutOfRange { mesh: mesh, index: to });
+            }
+        }
+
+        for mesh in self.meshes.iter_mut().filter(|x| x.material_index == from) {
+            mesh.material_index = to;
+        }
+        self.validate()
+    }
+
+    /// Removes a material from the scene, updating the material indices of all meshes.
+    ///
+    /// Fails if the material is used by a mesh. Use
+    /// [`reassign_material`](#method.reassign_material) first to change the material of those
+    /// meshes.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn remove_material(&mut self, index: u32) -> Result<OwnedMaterial, ValidationError> {
+        if let Some(mesh) = self.meshes.iter().position(|x| x.material_index == index) {
+            return Err(MaterialInUse { index: index, mesh: mesh });
+        }
+
+        let material = self.materials.remove(index as usize);
+        for mesh in self.meshes.iter_mut().filter(|x| x.material_index > index) {
+            mesh.material_index -= 1;
+        }
+        try!(self.validate());
+        Ok(material)
+    }
+}
+
+fn find_node<'a>(node: &'a OwnedNode, name: &str) -> Option<&'a OwnedNode> {
+    if node.name == name {
+        return Some(node);
+    }
+    node.children.iter().filter_map(|x| find_node(x, name)).next()
+}
+
+fn find_node_mut<'a>(node: &'a mut OwnedNode, name: &str) -> Option<&'a mut OwnedNode> {
+    if node.name == name {
+        return Some(node);
+    }
+    node.children.iter_mut().filter_map(|x| find_node_mut(x, name)).next()
+}
+
+fn count_nodes(node: &OwnedNode, name: &str) -> usize {
+    let count = if node.name == name { 1 } else { 0 };
+    count + node.children.iter().map(|x| count_nodes(x, name)).sum::<usize>()
+}
+
+// Removes a descendant of `node`, searching in the same order as `find_node`
+fn take_node(node: &mut OwnedNode, name: &str) -> Option<OwnedNode> {
+    for i in 0..node.children.len() {
+        if node.children[i].name == name {
+            return Some(node.children.remove(i));
+        }
+        if let Some(child) = take_node(&mut node.children[i], name) {
+            return Some(child);
+        }
+    }
+    None
+}
+
+fn remove_mesh_index(node: &mut OwnedNode, index: u32) {
+    node.meshes.retain(|&x| x != index);
+    for mesh in node.meshes.iter_mut().filter(|x| **x > index) {
+        *mesh -= 1;
+    }
+    for child in &mut node.children {
+        remove_mesh_index(child, index);
+    }
+}
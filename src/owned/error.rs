@@ -0,0 +1,160 @@
+//! Error types returned when validating or building a scene.
+
+use std::error::Error;
+use std::fmt;
+
+/// Describes why an `OwnedScene` is invalid, see `OwnedScene::validate`, or why an edit to it
+/// can't be made.
+///
+/// Meshes, faces, materials and textures are identified by their index.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidationError {
+    /// A node refers to a mesh which doesn't exist.
+    MeshIndexOutOfRange {
+        node: String,
+        index: u32
+    },
+    /// A mesh refers to a material which doesn't exist.
+    MaterialIndexOutOfRange {
+        mesh: usize,
+        index: u32
+    },
+    /// A mesh has no vertices.
+    EmptyMesh {
+        mesh: usize
+    },
+    /// A per-vertex array of a mesh doesn't have one element per vertex.
+    VertexCountMismatch {
+        mesh: usize,
+        /// The name of the array, e.g. `"normals"`.
+        array: &'static str
+    },
+    /// A mesh has more color sets or texture coordinate channels than Assimp supports, or
+    /// `num_uv_components` doesn't match `texture_coords`.
+    InvalidChannels {
+        mesh: usize
+    },
+    /// A face has no indices.
+    EmptyFace {
+        mesh: usize,
+        face: usize
+    },
+    /// A face or bone refers to a vertex which doesn't exist.
+    VertexIndexOutOfRange {
+        mesh: usize,
+        index: u32
+    },
+    /// The size of a texture's data doesn't match its width and height.
+    TextureSizeMismatch {
+        texture: usize
+    },
+    /// A name or string property is longer than Assimp supports.
+    NameTooLong {
+        name: String
+    },
+    /// A bone or animation channel refers to a node which doesn't exist.
+    MissingNode {
+        name: String
+    },
+    /// A mesh animation channel refers to a mesh which doesn't exist.
+    MissingMesh {
+        name: String
+    },
+    /// An edit refers to a node by a name which several nodes have, or would give a node a name
+    /// which another node already has.
+    DuplicateNode {
+        name: String
+    },
+    /// A node can't be moved to the given parent, because it is the root node, or the parent is
+    /// the node itself or one of its descendants.
+    InvalidParent {
+        node: String,
+        parent: String
+    },
+    /// A material can't be removed because a mesh uses it.
+    MaterialInUse {
+        index: u32,
+        mesh: usize
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::ValidationError::*;
+        match *self {
+            MeshIndexOutOfRange { ref node, index } => {
+                write!(f, "node \"{}\" refers to mesh {} which doesn't exist", node, index)
+            }
+            MaterialIndexOutOfRange { mesh, index } => {
+                write!(f, "mesh {} refers to material {} which doesn't exist", mesh, index)
+            }
+            EmptyMesh { mesh } => write!(f, "mesh {} has no vertices", mesh),
+            VertexCountMismatch { mesh, array } => {
+                write!(f, "{} of mesh {} don't match the number of vertices", array, mesh)
+            }
+            InvalidChannels { mesh } => {
+                write!(f, "mesh {} has invalid color or texture coordinate channels", mesh)
+            }
+            EmptyFace { mesh, face } => write!(f, "face {} of mesh {} has no indices", face, mesh),
+            VertexIndexOutOfRange { mesh, index } => {
+                write!(f, "mesh {} refers to vertex {} which doesn't exist", mesh, index)
+            }
+            TextureSizeMismatch { texture } => {
+                write!(f, "data of texture {} doesn't match its size", texture)
+            }
+            NameTooLong { ref name } => write!(f, "name \"{}\" is too long", name),
+            MissingNode { ref name } => write!(f, "node \"{}\" doesn't exist", name),
+            MissingMesh { ref name } => write!(f, "mesh \"{}\" doesn't exist", name),
+            DuplicateNode { ref name } => write!(f, "several nodes are called \"{}\"", name),
+            InvalidParent { ref node, ref parent } => {
+                write!(f, "node \"{}\" can't be moved to \"{}\"", node, parent)
+            }
+            MaterialInUse { index, mesh } => {
+                write!(f, "material {} is used by mesh {}", index, mesh)
+            }
+        }
+    }
+}
+
+impl Error for ValidationError {
+    fn description(&self) -> &str {
+        "invalid scene"
+    }
+}
+
+/// Describes why building a `Scene` from an `OwnedScene` failed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BuildError {
+    /// The owned scene is invalid.
+    Invalid(ValidationError),
+    /// Assimp failed to copy the scene data.
+    CopyFailed
+}
+
+impl From<ValidationError> for BuildError {
+    fn from(err: ValidationError) -> BuildError {
+        BuildError::Invalid(err)
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BuildError::Invalid(ref err) => write!(f, "failed to build scene: {}", err),
+            BuildError::CopyFailed => write!(f, "failed to build scene: Assimp couldn't copy it")
+        }
+    }
+}
+
+impl Error for BuildError {
+    fn description(&self) -> &str {
+        "failed to build scene"
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            BuildError::Invalid(ref err) => Some(err),
+            BuildError::CopyFailed => None
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use ffi::AiLightSourceType;
+
+use math::{Color3D, Vector3D};
+use scene::Light;
+
+/// An owned copy of a `Light`.
+///
+/// The position and direction are relative to the node with the same name as the light.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedLight {
+    pub name: String,
+    pub light_type: LightSourceType,
+    pub position: Vector3D,
+    pub direction: Vector3D,
+    pub attenuation_constant: f32,
+    pub attenuation_linear: f32,
+    pub attenuation_quadratic: f32,
+    pub color_diffuse: Color3D,
+    pub color_specular: Color3D,
+    pub color_ambient: Color3D,
+    /// Inner angle of a spot light's light cone, in radians.
+    pub angle_inner_cone: f32,
+    /// Outer angle of a spot light's light cone, in radians.
+    pub angle_outer_cone: f32
+}
+
+/// Enumerates the types of light sources.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LightSourceType {
+    Undefined,
+    Directional,
+    Point,
+    Spot
+}
+
+impl From<AiLightSourceType> for LightSourceType {
+    fn from(light_type: AiLightSourceType) -> LightSourceType {
+        match light_type {
+            AiLightSourceType::Undefined => LightSourceType::Undefined,
+            AiLightSourceType::Directional => LightSourceType::Directional,
+            AiLightSourceType::Point => LightSourceType::Point,
+            AiLightSourceType::Spot => LightSourceType::Spot
+        }
+    }
+}
+
+impl<'a, 'b> From<&'b Light<'a>> for OwnedLight {
+    fn from(light: &'b Light<'a>) -> OwnedLight {
+        unsafe {
+            OwnedLight {
+                name: super::to_string(&light.name),
+                light_type: From::from(light.light_type),
+                position: Vector3D::from_raw(&light.position),
+                direction: Vector3D::from_raw(&light.direction),
+                attenuation_constant: light.attenuation_constant,
+                attenuation_linear: light.attenuation_linear,
+                attenuation_quadratic: light.attenuation_quadratic,
+                color_diffuse: Color3D::from_raw(&light.color_diffuse),
+                color_specular: Color3D::from_raw(&light.color_specular),
+                color_ambient: Color3D::from_raw(&light.color_ambient),
+                angle_inner_cone: light.angle_inner_cone,
+                angle_outer_cone: light.angle_outer_cone
+            }
+        }
+    }
+}
+
+impl<'a> From<Light<'a>> for OwnedLight {
+    fn from(light: Light<'a>) -> OwnedLight {
+        OwnedLight::from(&light)
+    }
+}
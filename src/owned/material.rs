@@ -0,0 +1,129 @@
+use std::mem;
+use std::ptr;
+
+use ffi::{AiMaterialProperty, AiPropertyTypeInfo};
+
+use scene::Material;
+
+use super::raw_slice;
+
+/// An owned copy of a `Material`, stored as the list of its properties.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedMaterial {
+    pub properties: Vec<OwnedMaterialProperty>
+}
+
+/// A single property of an `OwnedMaterial`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedMaterialProperty {
+    /// The name of the property, e.g. `"$clr.diffuse"`.
+    pub key: String,
+    /// The texture type for texture properties, otherwise 0.
+    pub semantic: u32,
+    /// The texture index for texture properties, otherwise 0.
+    pub index: u32,
+    pub data: MaterialPropertyData
+}
+
+/// The value of an `OwnedMaterialProperty`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MaterialPropertyData {
+    Float(Vec<f32>),
+    String(String),
+    Integer(Vec<i32>),
+    /// Data of any other type.
+    Buffer(Vec<u8>)
+}
+
+impl OwnedMaterial {
+    /// Create a material with the given name and no other properties.
+    pub fn new(name: &str) -> OwnedMaterial {
+        let mut material = OwnedMaterial { properties: Vec::new() };
+        material.set_property("?mat.name", 0, 0, MaterialPropertyData::String(name.to_owned()));
+        material
+    }
+
+    /// Sets the property with the given key, semantic and index, replacing any existing value.
+    pub fn set_property(&mut self, key: &str, semantic: u32, index: u32,
+                        data: MaterialPropertyData) {
+        let existing = self.properties.iter_mut().find(|x| {
+            x.key == key && x.semantic == semantic && x.index == index
+        });
+        match existing {
+            Some(property) => property.data = data,
+            None => self.properties.push(OwnedMaterialProperty {
+                key: key.to_owned(),
+                semantic: semantic,
+                index: index,
+                data: data
+            })
+        }
+    }
+
+    /// Returns the property with the given key, semantic and index.
+    pub fn property(&self, key: &str, semantic: u32, index: u32)
+                    -> Option<&OwnedMaterialProperty> {
+        self.properties.iter().find(|x| x.key == key && x.semantic == semantic && x.index == index)
+    }
+
+    /// Returns the name of the material, if it has one.
+    pub fn name(&self) -> Option<&str> {
+        match self.property("?mat.name", 0, 0) {
+            Some(&OwnedMaterialProperty { data: MaterialPropertyData::String(ref name), .. }) => {
+                Some(name)
+            }
+            _ => None
+        }
+    }
+}
+
+impl<'a, 'b> From<&'b Material<'a>> for OwnedMaterial {
+    fn from(material: &'b Material<'a>) -> OwnedMaterial {
+        let properties = unsafe {
+            raw_slice(material.properties, material.num_properties as usize)
+        };
+        OwnedMaterial {
+            properties: properties.iter().map(|&x| unsafe { property(&*x) }).collect()
+        }
+    }
+}
+
+impl<'a> From<Material<'a>> for OwnedMaterial {
+    fn from(material: Material<'a>) -> OwnedMaterial {
+        OwnedMaterial::from(&material)
+    }
+}
+
+unsafe fn property(property: &AiMaterialProperty) -> OwnedMaterialProperty {
+    let bytes = raw_slice(property.data as *const u8, property.data_length as usize);
+    let data = match property.property_type {
+        AiPropertyTypeInfo::Float => MaterialPropertyData::Float(read_all(bytes)),
+        AiPropertyTypeInfo::Integer => MaterialPropertyData::Integer(read_all(bytes)),
+        // Strings are stored as a 32-bit length followed by the NUL terminated string
+        AiPropertyTypeInfo::String if bytes.len() >= 4 => {
+            let len: u32 = ptr::read_unaligned(bytes.as_ptr() as *const u32);
+            let end = (4 + len as usize).min(bytes.len());
+            MaterialPropertyData::String(String::from_utf8_lossy(&bytes[4..end]).into_owned())
+        }
+        _ => MaterialPropertyData::Buffer(bytes.to_vec())
+    };
+
+    OwnedMaterialProperty {
+        key: super::to_string(&property.key),
+        semantic: property.semantic,
+        index: property.index,
+        data: data
+    }
+}
+
+// Material data isn't necessarily aligned
+unsafe fn read_all<T: Copy>(bytes: &[u8]) -> Vec<T> {
+    let size = mem::size_of::<T>();
+    bytes.chunks(size)
+         .filter(|x| x.len() == size)
+         .map(|x| ptr::read_unaligned(x.as_ptr() as *const T))
+         .collect()
+}
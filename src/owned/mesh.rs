@@ -0,0 +1,175 @@
+use ffi::{AiAnimMesh, AiBone, AiColor4D, AiVector3D};
+use ffi::{AIPRIMITIVETYPE_LINE, AIPRIMITIVETYPE_POINT, AIPRIMITIVETYPE_POLYGON,
+          AIPRIMITIVETYPE_TRIANGLE};
+
+use math::{Color4D, Matrix4x4, Vector3D};
+use scene::Mesh;
+
+use super::raw_slice;
+
+/// An owned copy of a `Mesh`.
+///
+/// All per-vertex arrays are either empty or contain one element per vertex.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedMesh {
+    pub name: String,
+    /// The `AIPRIMITIVETYPE_*` flags of the primitives used by this mesh.
+    pub primitive_types: u32,
+    pub vertices: Vec<Vector3D>,
+    pub normals: Vec<Vector3D>,
+    pub tangents: Vec<Vector3D>,
+    pub bitangents: Vec<Vector3D>,
+    /// Vertex color sets. A set which is missing in the middle of the list is empty.
+    pub colors: Vec<Vec<Color4D>>,
+    /// Texture coordinate channels. A channel which is missing in the middle of the list is empty.
+    pub texture_coords: Vec<Vec<Vector3D>>,
+    /// The number of components used by each channel in `texture_coords`.
+    pub num_uv_components: Vec<u32>,
+    /// The vertex indices of each face.
+    pub faces: Vec<Vec<u32>>,
+    pub bones: Vec<OwnedBone>,
+    /// Index into `OwnedScene::materials` of the material used by this mesh.
+    pub material_index: u32,
+    pub anim_meshes: Vec<OwnedAnimMesh>
+}
+
+/// A bone of an `OwnedMesh`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedBone {
+    /// The name of the node the bone is attached to.
+    pub name: String,
+    pub weights: Vec<OwnedVertexWeight>,
+    /// Transforms from mesh space to bone space in bind pose.
+    pub offset_matrix: Matrix4x4
+}
+
+/// The influence of a bone on a single vertex.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct OwnedVertexWeight {
+    pub vertex_id: u32,
+    pub weight: f32
+}
+
+/// An attachment of an `OwnedMesh`, which replaces some of its vertex data for a mesh animation.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedAnimMesh {
+    pub vertices: Vec<Vector3D>,
+    pub normals: Vec<Vector3D>,
+    pub tangents: Vec<Vector3D>,
+    pub bitangents: Vec<Vector3D>,
+    pub colors: Vec<Vec<Color4D>>,
+    pub texture_coords: Vec<Vec<Vector3D>>
+}
+
+impl OwnedMesh {
+    /// Create a mesh from its vertices and faces, using the first material of the scene.
+    ///
+    /// `primitive_types` is set from the number of indices of each face, and all other vertex
+    /// data is empty.
+    pub fn new(name: &str, vertices: Vec<Vector3D>, faces: Vec<Vec<u32>>) -> OwnedMesh {
+        let primitive_types = faces.iter().fold(0, |x, face| x | match face.len() {
+            1 => AIPRIMITIVETYPE_POINT,
+            2 => AIPRIMITIVETYPE_LINE,
+            3 => AIPRIMITIVETYPE_TRIANGLE,
+            _ => AIPRIMITIVETYPE_POLYGON
+        }.bits());
+
+        OwnedMesh {
+            name: name.to_owned(),
+            primitive_types: primitive_types,
+            vertices: vertices,
+            normals: Vec::new(),
+            tangents: Vec::new(),
+            bitangents: Vec::new(),
+            colors: Vec::new(),
+            texture_coords: Vec::new(),
+            num_uv_components: Vec::new(),
+            faces: faces,
+            bones: Vec::new(),
+            material_index: 0,
+            anim_meshes: Vec::new()
+        }
+    }
+}
+
+impl<'a, 'b> From<&'b Mesh<'a>> for OwnedMesh {
+    fn from(mesh: &'b Mesh<'a>) -> OwnedMesh {
+        let len = mesh.num_vertices as usize;
+        let texture_coords = channels(&mesh.texture_coords, |x| unsafe { vectors(x, len) });
+        let num_uv_components = mesh.num_uv_components[..texture_coords.len()].to_vec();
+
+        unsafe {
+            OwnedMesh {
+                name: super::to_string(&mesh.name),
+                primitive_types: mesh.primitive_types,
+                vertices: vectors(mesh.vertices, len),
+                normals: vectors(mesh.normals, len),
+                tangents: vectors(mesh.tangents, len),
+                bitangents: vectors(mesh.bitangents, len),
+                colors: channels(&mesh.colors, |x| colors(x, len)),
+                texture_coords: texture_coords,
+                num_uv_components: num_uv_components,
+                faces: raw_slice(mesh.faces, mesh.num_faces as usize).iter().map(|x| {
+                    raw_slice(x.indices, x.num_indices as usize).to_vec()
+                }).collect(),
+                bones: raw_slice(mesh.bones, mesh.num_bones as usize).iter().map(|&x| {
+                    bone(&*x)
+                }).collect(),
+                material_index: mesh.material_index,
+                anim_meshes: raw_slice(mesh.anim_meshes, mesh.num_anim_meshes as usize)
+                    .iter()
+                    .map(|&x| anim_mesh(&*x))
+                    .collect()
+            }
+        }
+    }
+}
+
+impl<'a> From<Mesh<'a>> for OwnedMesh {
+    fn from(mesh: Mesh<'a>) -> OwnedMesh {
+        OwnedMesh::from(&mesh)
+    }
+}
+
+unsafe fn bone(bone: &AiBone) -> OwnedBone {
+    OwnedBone {
+        name: super::to_string(&bone.name),
+        weights: raw_slice(bone.weights, bone.num_weights as usize).iter().map(|x| {
+            OwnedVertexWeight { vertex_id: x.vertex_id, weight: x.weight }
+        }).collect(),
+        offset_matrix: Matrix4x4::from_raw(&bone.offset_matrix)
+    }
+}
+
+unsafe fn anim_mesh(anim_mesh: &AiAnimMesh) -> OwnedAnimMesh {
+    let len = anim_mesh.num_vertices as usize;
+    OwnedAnimMesh {
+        vertices: vectors(anim_mesh.vertices, len),
+        normals: vectors(anim_mesh.normals, len),
+        tangents: vectors(anim_mesh.tangents, len),
+        bitangents: vectors(anim_mesh.bitangents, len),
+        colors: channels(&anim_mesh.colors, |x| colors(x, len)),
+        texture_coords: channels(&anim_mesh.texture_coords, |x| vectors(x, len))
+    }
+}
+
+unsafe fn vectors(ptr: *const AiVector3D, len: usize) -> Vec<Vector3D> {
+    raw_slice(ptr, len).iter().map(|x| Vector3D::from_raw(x)).collect()
+}
+
+unsafe fn colors(ptr: *const AiColor4D, len: usize) -> Vec<Color4D> {
+    raw_slice(ptr, len).iter().map(|x| Color4D::from_raw(x)).collect()
+}
+
+// Reads each channel up to the last one which is present
+fn channels<T, U, F>(ptrs: &[*mut T], read: F) -> Vec<Vec<U>>
+    where F: Fn(*const T) -> Vec<U>
+{
+    let len = ptrs.iter().rposition(|x| !x.is_null()).map_or(0, |i| i + 1);
+    ptrs[..len].iter().map(|&x| read(x)).collect()
+}
@@ -0,0 +1,72 @@
+//! The `owned` module contains a plain Rust copy of the scene data.
+//!
+//! The types in the [`scene`](../scene/index.html) module borrow data owned by Assimp, so they
+//! can't outlive the `Scene` they were read from. The types in this module own all of their data
+//! instead, so a scene can be converted to an `OwnedScene` and the Assimp scene dropped right
+//! after import. Owned data can be freely modified, stored and sent between threads.
+//!
+//! Arrays which are missing from the Assimp scene (e.g. the normals of a mesh without normals)
+//! are empty in the owned data.
+//!
+//! With the `serde` feature enabled the owned types implement `Serialize` and `Deserialize`, and
+//! the scene views implement `Serialize` producing the same output as their owned counterparts.
+//! This allows dumping a `Scene` to e.g. JSON and loading it back as an `OwnedScene`.
+//!
+//! # Examples
+//! ```
+//! use assimp::Importer;
+//! use assimp::owned::OwnedScene;
+//!
+//! let scene = Importer::new().read_file("examples/box.obj").unwrap();
+//! let owned = OwnedScene::from(&scene);
+//! drop(scene);
+//!
+//! println!("{} vertices", owned.meshes[0].vertices.len());
+//! ```
+
+use std::slice;
+
+use ffi::AiString;
+
+pub use self::animation::*;
+pub use self::builder::SceneBuilder;
+pub use self::camera::*;
+pub use self::error::{BuildError, ValidationError};
+pub use self::light::*;
+pub use self::material::*;
+pub use self::mesh::*;
+pub use self::node::*;
+pub use self::scene::OwnedScene;
+pub use self::texture::*;
+
+mod animation;
+mod builder;
+mod camera;
+mod edit;
+mod error;
+mod light;
+mod material;
+mod mesh;
+mod node;
+mod raw;
+mod scene;
+#[cfg(feature = "serde")]
+mod serialize;
+mod texture;
+mod validate;
+
+// Returns an empty slice for null pointers, which Assimp uses for missing arrays.
+// Unsafe as the caller must ensure the data outlives the chosen lifetime
+unsafe fn raw_slice<'a, T>(ptr: *const T, len: usize) -> &'a [T] {
+    if ptr.is_null() || len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(ptr, len)
+    }
+}
+
+// Unlike `AiString::as_ref`, doesn't panic on invalid UTF-8
+fn to_string(s: &AiString) -> String {
+    let len = (s.length as usize).min(s.data.len());
+    String::from_utf8_lossy(&s.data[..len]).into_owned()
+}
@@ -0,0 +1,45 @@
+use cgmath::Matrix4;
+
+use math::Matrix4x4;
+use scene::Node;
+
+/// An owned copy of a `Node` and all of its children.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedNode {
+    pub name: String,
+    /// Transformation relative to the parent node.
+    pub transformation: Matrix4x4,
+    /// Indices into `OwnedScene::meshes` of the meshes under this node.
+    pub meshes: Vec<u32>,
+    pub children: Vec<OwnedNode>
+}
+
+impl OwnedNode {
+    /// Create a node with an identity transformation and no meshes or children.
+    pub fn new(name: &str) -> OwnedNode {
+        OwnedNode {
+            name: name.to_owned(),
+            transformation: Matrix4x4::from(Matrix4::<f32>::identity()),
+            meshes: Vec::new(),
+            children: Vec::new()
+        }
+    }
+}
+
+impl<'a, 'b> From<&'b Node<'a>> for OwnedNode {
+    fn from(node: &'b Node<'a>) -> OwnedNode {
+        OwnedNode {
+            name: super::to_string(&node.name),
+            transformation: node.transformation(),
+            meshes: unsafe { super::raw_slice(node.meshes, node.num_meshes as usize).to_vec() },
+            children: node.child_iter().map(From::from).collect()
+        }
+    }
+}
+
+impl<'a> From<Node<'a>> for OwnedNode {
+    fn from(node: Node<'a>) -> OwnedNode {
+        OwnedNode::from(&node)
+    }
+}
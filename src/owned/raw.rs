@@ -0,0 +1,405 @@
+//! A raw Assimp scene allocated by Rust, used to pass an `OwnedScene` to Assimp.
+//!
+//! Assimp frees scenes with C++ `delete`, so memory allocated here must never be released by
+//! Assimp. The raw scene is only passed to functions which copy it (e.g. `aiCopyScene`), and is
+//! freed by `RawScene` when dropped.
+
+use std::mem;
+use std::ptr;
+use std::slice;
+
+use ffi::*;
+use libc::{c_char, size_t};
+
+use math::Vector3D;
+
+use super::*;
+
+pub struct RawScene(*mut AiScene);
+
+impl RawScene {
+    // The scene must be valid, see `OwnedScene::validate`
+    pub fn new(scene: &OwnedScene) -> RawScene {
+        let mut raw: AiScene = unsafe { mem::zeroed() };
+        raw.flags = AiSceneFlags::from_bits_truncate(scene.flags);
+        raw.root_node = node(&scene.root_node, ptr::null_mut());
+        raw.num_meshes = scene.meshes.len() as u32;
+        raw.meshes = boxed_array(scene.meshes.iter().map(mesh));
+        raw.num_materials = scene.materials.len() as u32;
+        raw.materials = boxed_array(scene.materials.iter().map(material));
+        raw.num_animations = scene.animations.len() as u32;
+        raw.animations = boxed_array(scene.animations.iter().map(animation));
+        raw.num_textures = scene.textures.len() as u32;
+        raw.textures = boxed_array(scene.textures.iter().map(texture));
+        raw.num_lights = scene.lights.len() as u32;
+        raw.lights = boxed_array(scene.lights.iter().map(light));
+        raw.num_cameras = scene.cameras.len() as u32;
+        raw.cameras = boxed_array(scene.cameras.iter().map(camera));
+        RawScene(Box::into_raw(Box::new(raw)))
+    }
+
+    pub fn as_ptr(&self) -> *const AiScene {
+        self.0
+    }
+}
+
+impl Drop for RawScene {
+    fn drop(&mut self) {
+        unsafe {
+            let scene = Box::from_raw(self.0);
+            free_node(scene.root_node);
+            free_boxed_array(scene.meshes, scene.num_meshes, |x| free_mesh(x));
+            free_boxed_array(scene.materials, scene.num_materials, |x| free_material(x));
+            free_boxed_array(scene.animations, scene.num_animations, |x| free_animation(x));
+            free_boxed_array(scene.textures, scene.num_textures, |x| free_texture(x));
+            free_boxed_array(scene.lights, scene.num_lights, |x| drop(Box::from_raw(x)));
+            free_boxed_array(scene.cameras, scene.num_cameras, |x| drop(Box::from_raw(x)));
+        }
+    }
+}
+
+// Assimp uses null pointers for empty arrays
+fn array<T>(items: Vec<T>) -> *mut T {
+    if items.is_empty() {
+        ptr::null_mut()
+    } else {
+        Box::into_raw(items.into_boxed_slice()) as *mut T
+    }
+}
+
+unsafe fn free_array<T>(ptr: *mut T, len: usize) {
+    if !ptr.is_null() {
+        drop(Box::from_raw(slice::from_raw_parts_mut(ptr, len) as *mut [T]));
+    }
+}
+
+fn boxed_array<T, I: Iterator<Item=T>>(items: I) -> *mut *mut T {
+    array(items.map(|x| Box::into_raw(Box::new(x))).collect())
+}
+
+unsafe fn free_boxed_array<T, F: Fn(*mut T)>(ptr: *mut *mut T, len: u32, free: F) {
+    if !ptr.is_null() {
+        for &item in slice::from_raw_parts(ptr, len as usize) {
+            free(item);
+        }
+        free_array(ptr, len as usize);
+    }
+}
+
+fn string(s: &str) -> AiString {
+    let mut raw = AiString::default();
+    let len = s.len().min(MAXLEN - 1);
+    raw.data[..len].copy_from_slice(&s.as_bytes()[..len]);
+    raw.length = len as size_t;
+    raw
+}
+
+fn vectors(vectors: &[Vector3D]) -> *mut AiVector3D {
+    array(vectors.iter().map(|x| **x).collect())
+}
+
+fn node(node: &OwnedNode, parent: *mut AiNode) -> *mut AiNode {
+    let raw = Box::into_raw(Box::new(AiNode {
+        name: string(&node.name),
+        transformation: *node.transformation,
+        parent: parent,
+        num_children: node.children.len() as u32,
+        children: ptr::null_mut(),
+        num_meshes: node.meshes.len() as u32,
+        meshes: array(node.meshes.clone()),
+        metadata: ptr::null_mut()
+    }));
+    // Children are created after the parent so they can point to it
+    let children = node.children.iter().map(|x| self::node(x, raw)).collect();
+    unsafe { (*raw).children = array(children) };
+    raw
+}
+
+unsafe fn free_node(node: *mut AiNode) {
+    let node = Box::from_raw(node);
+    free_array(node.meshes, node.num_meshes as usize);
+    free_boxed_array(node.children, node.num_children, |x| free_node(x));
+}
+
+fn mesh(mesh: &OwnedMesh) -> AiMesh {
+    let mut colors = [ptr::null_mut(); AI_MAX_NUMBER_OF_COLOR_SETS];
+    for (raw, colors) in colors.iter_mut().zip(&mesh.colors) {
+        *raw = array(colors.iter().map(|x| **x).collect());
+    }
+    let mut texture_coords = [ptr::null_mut(); AI_MAX_NUMBER_OF_TEXTURECOORDS];
+    for (raw, texture_coords) in texture_coords.iter_mut().zip(&mesh.texture_coords) {
+        *raw = vectors(texture_coords);
+    }
+    let mut num_uv_components = [0; AI_MAX_NUMBER_OF_TEXTURECOORDS];
+    for (raw, &num) in num_uv_components.iter_mut().zip(&mesh.num_uv_components) {
+        *raw = num;
+    }
+
+    AiMesh {
+        primitive_types: mesh.primitive_types,
+        num_vertices: mesh.vertices.len() as u32,
+        num_faces: mesh.faces.len() as u32,
+        vertices: vectors(&mesh.vertices),
+        normals: vectors(&mesh.normals),
+        tangents: vectors(&mesh.tangents),
+        bitangents: vectors(&mesh.bitangents),
+        colors: colors,
+        texture_coords: texture_coords,
+        num_uv_components: num_uv_components,
+        faces: array(mesh.faces.iter().map(|x| {
+            AiFace { num_indices: x.len() as u32, indices: array(x.clone()) }
+        }).collect()),
+        num_bones: mesh.bones.len() as u32,
+        bones: boxed_array(mesh.bones.iter().map(|x| {
+            AiBone {
+                name: string(&x.name),
+                num_weights: x.weights.len() as u32,
+                weights: array(x.weights.iter().map(|x| {
+                    AiVertexWeight { vertex_id: x.vertex_id, weight: x.weight }
+                }).collect()),
+                offset_matrix: *x.offset_matrix
+            }
+        })),
+        material_index: mesh.material_index,
+        name: string(&mesh.name),
+        num_anim_meshes: mesh.anim_meshes.len() as u32,
+        anim_meshes: boxed_array(mesh.anim_meshes.iter().map(|x| {
+            anim_mesh(x, mesh.vertices.len())
+        }))
+    }
+}
+
+// Anim meshes have the same number of vertices as their mesh
+fn anim_mesh(anim_mesh: &OwnedAnimMesh, num_vertices: usize) -> AiAnimMesh {
+    let mut colors = [ptr::null_mut(); AI_MAX_NUMBER_OF_COLOR_SETS];
+    for (raw, colors) in colors.iter_mut().zip(&anim_mesh.colors) {
+        *raw = array(colors.iter().map(|x| **x).collect());
+    }
+    let mut texture_coords = [ptr::null_mut(); AI_MAX_NUMBER_OF_TEXTURECOORDS];
+    for (raw, texture_coords) in texture_coords.iter_mut().zip(&anim_mesh.texture_coords) {
+        *raw = vectors(texture_coords);
+    }
+
+    AiAnimMesh {
+        vertices: vectors(&anim_mesh.vertices),
+        normals: vectors(&anim_mesh.normals),
+        tangents: vectors(&anim_mesh.tangents),
+        bitangents: vectors(&anim_mesh.bitangents),
+        colors: colors,
+        texture_coords: texture_coords,
+        num_vertices: num_vertices as u32
+    }
+}
+
+unsafe fn free_mesh(mesh: *mut AiMesh) {
+    let mesh = Box::from_raw(mesh);
+    let len = mesh.num_vertices as usize;
+    free_array(mesh.vertices, len);
+    free_array(mesh.normals, len);
+    free_array(mesh.tangents, len);
+    free_array(mesh.bitangents, len);
+    for &colors in &mesh.colors {
+        free_array(colors, len);
+    }
+    for &texture_coords in &mesh.texture_coords {
+        free_array(texture_coords, len);
+    }
+    if !mesh.faces.is_null() {
+        for face in slice::from_raw_parts(mesh.faces, mesh.num_faces as usize) {
+            free_array(face.indices, face.num_indices as usize);
+        }
+        free_array(mesh.faces, mesh.num_faces as usize);
+    }
+    free_boxed_array(mesh.bones, mesh.num_bones, |x| {
+        let bone = Box::from_raw(x);
+        free_array(bone.weights, bone.num_weights as usize);
+    });
+    free_boxed_array(mesh.anim_meshes, mesh.num_anim_meshes, |x| {
+        let anim_mesh = Box::from_raw(x);
+        let len = anim_mesh.num_vertices as usize;
+        free_array(anim_mesh.vertices, len);
+        free_array(anim_mesh.normals, len);
+        free_array(anim_mesh.tangents, len);
+        free_array(anim_mesh.bitangents, len);
+        for &colors in &anim_mesh.colors {
+            free_array(colors, len);
+        }
+        for &texture_coords in &anim_mesh.texture_coords {
+            free_array(texture_coords, len);
+        }
+    });
+}
+
+fn material(material: &OwnedMaterial) -> AiMaterial {
+    let properties = material.properties.iter().map(|x| {
+        let (property_type, data) = match x.data {
+            MaterialPropertyData::Float(ref values) => {
+                (AiPropertyTypeInfo::Float, values.iter().flat_map(|x| bytes(x)).collect())
+            }
+            MaterialPropertyData::Integer(ref values) => {
+                (AiPropertyTypeInfo::Integer, values.iter().flat_map(|x| bytes(x)).collect())
+            }
+            // Strings are stored as a 32-bit length followed by the NUL terminated string
+            MaterialPropertyData::String(ref value) => {
+                let value = string(value);
+                let mut data = bytes(&(value.length as u32));
+                data.extend_from_slice(&value.data[..value.length as usize]);
+                data.push(0);
+                (AiPropertyTypeInfo::String, data)
+            }
+            MaterialPropertyData::Buffer(ref data) => (AiPropertyTypeInfo::Buffer, data.clone())
+        };
+
+        AiMaterialProperty {
+            key: string(&x.key),
+            semantic: x.semantic,
+            index: x.index,
+            data_length: data.len() as u32,
+            property_type: property_type,
+            data: array(data) as *mut c_char
+        }
+    });
+
+    AiMaterial {
+        num_properties: material.properties.len() as u32,
+        num_allocated: material.properties.len() as u32,
+        properties: boxed_array(properties)
+    }
+}
+
+unsafe fn free_material(material: *mut AiMaterial) {
+    let material = Box::from_raw(material);
+    free_boxed_array(material.properties, material.num_properties, |x| {
+        let property = Box::from_raw(x);
+        free_array(property.data as *mut u8, property.data_length as usize);
+    });
+}
+
+fn bytes<T: Copy>(value: &T) -> Vec<u8> {
+    let ptr = value as *const T as *const u8;
+    unsafe { slice::from_raw_parts(ptr, mem::size_of::<T>()).to_vec() }
+}
+
+fn animation(animation: &OwnedAnimation) -> AiAnimation {
+    let vector_keys = |keys: &[OwnedVectorKey]| {
+        array(keys.iter().map(|x| AiVectorKey { time: x.time, value: *x.value }).collect())
+    };
+    let channels = animation.channels.iter().map(|x| {
+        AiNodeAnim {
+            node_name: string(&x.node_name),
+            num_position_keys: x.position_keys.len() as u32,
+            position_keys: vector_keys(&x.position_keys),
+            num_rotation_keys: x.rotation_keys.len() as u32,
+            rotation_keys: array(x.rotation_keys.iter().map(|x| {
+                AiQuatKey { time: x.time, velue: *x.value }
+            }).collect()),
+            num_scaling_keys: x.scaling_keys.len() as u32,
+            scaling_keys: vector_keys(&x.scaling_keys),
+            pre_state: anim_behaviour(x.pre_state),
+            post_state: anim_behaviour(x.post_state)
+        }
+    });
+    let mesh_channels = animation.mesh_channels.iter().map(|x| {
+        AiMeshAnim {
+            name: string(&x.name),
+            num_keys: x.keys.len() as u32,
+            keys: array(x.keys
+                          .iter()
+                          .map(|x| AiMeshKey { time: x.time, value: x.value })
+                          .collect())
+        }
+    });
+
+    AiAnimation {
+        name: string(&animation.name),
+        duration: animation.duration,
+        ticks_per_second: animation.ticks_per_second,
+        num_channels: animation.channels.len() as u32,
+        channels: boxed_array(channels),
+        num_mesh_channels: animation.mesh_channels.len() as u32,
+        mesh_channels: boxed_array(mesh_channels)
+    }
+}
+
+fn anim_behaviour(behaviour: AnimBehaviour) -> AiAnimBehaviour {
+    match behaviour {
+        AnimBehaviour::Default => AiAnimBehaviour::Default,
+        AnimBehaviour::Constant => AiAnimBehaviour::Constant,
+        AnimBehaviour::Linear => AiAnimBehaviour::Linear,
+        AnimBehaviour::Repeat => AiAnimBehaviour::Repeat
+    }
+}
+
+unsafe fn free_animation(animation: *mut AiAnimation) {
+    let animation = Box::from_raw(animation);
+    free_boxed_array(animation.channels, animation.num_channels, |x| {
+        let channel = Box::from_raw(x);
+        free_array(channel.position_keys, channel.num_position_keys as usize);
+        free_array(channel.rotation_keys, channel.num_rotation_keys as usize);
+        free_array(channel.scaling_keys, channel.num_scaling_keys as usize);
+    });
+    free_boxed_array(animation.mesh_channels, animation.num_mesh_channels, |x| {
+        let channel = Box::from_raw(x);
+        free_array(channel.keys, channel.num_keys as usize);
+    });
+}
+
+fn texture(texture: &OwnedTexture) -> AiTexture {
+    // The hint is at most 3 characters followed by a NUL terminator
+    let mut format_hint = [0; 4];
+    for (raw, &c) in format_hint.iter_mut().zip(texture.format_hint.as_bytes().iter().take(3)) {
+        *raw = c as c_char;
+    }
+
+    AiTexture {
+        width: texture.width,
+        height: texture.height,
+        format_hint: format_hint,
+        data: array(texture.data.clone()) as *mut AiTexel
+    }
+}
+
+unsafe fn free_texture(texture: *mut AiTexture) {
+    let texture = Box::from_raw(texture);
+    let len = if texture.height == 0 {
+        texture.width as usize
+    } else {
+        texture.width as usize * texture.height as usize * 4
+    };
+    free_array(texture.data as *mut u8, len);
+}
+
+fn light(light: &OwnedLight) -> AiLight {
+    AiLight {
+        name: string(&light.name),
+        light_type: match light.light_type {
+            LightSourceType::Undefined => AiLightSourceType::Undefined,
+            LightSourceType::Directional => AiLightSourceType::Directional,
+            LightSourceType::Point => AiLightSourceType::Point,
+            LightSourceType::Spot => AiLightSourceType::Spot
+        },
+        position: *light.position,
+        direction: *light.direction,
+        attenuation_constant: light.attenuation_constant,
+        attenuation_linear: light.attenuation_linear,
+        attenuation_quadratic: light.attenuation_quadratic,
+        color_diffuse: *light.color_diffuse,
+        color_specular: *light.color_specular,
+        color_ambient: *light.color_ambient,
+        angle_inner_cone: light.angle_inner_cone,
+        angle_outer_cone: light.angle_outer_cone
+    }
+}
+
+fn camera(camera: &OwnedCamera) -> AiCamera {
+    AiCamera {
+        name: string(&camera.name),
+        position: *camera.position,
+        up: *camera.up,
+        look_at: *camera.look_at,
+        horizontal_fov: camera.horizontal_fov,
+        clip_plane_near: camera.clip_plane_near,
+        clip_plane_far: camera.clip_plane_far,
+        aspect: camera.aspect
+    }
+}
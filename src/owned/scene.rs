@@ -0,0 +1,62 @@
+use std::ptr;
+
+use ffi::aiCopyScene;
+
+use scene::Scene;
+
+use super::error::BuildError;
+use super::raw::RawScene;
+use super::{OwnedAnimation, OwnedCamera, OwnedLight, OwnedMaterial, OwnedMesh, OwnedNode,
+            OwnedTexture};
+
+/// An owned copy of a `Scene`.
+///
+/// Meshes, materials and animations refer to each other by index, the same way as in the
+/// Assimp scene, e.g. `OwnedNode::meshes` contains indices into `meshes`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedScene {
+    /// The `AI_SCENE_FLAGS_*` flags of the scene.
+    pub flags: u32,
+    /// The root node of the scene hierarchy.
+    pub root_node: OwnedNode,
+    pub meshes: Vec<OwnedMesh>,
+    pub materials: Vec<OwnedMaterial>,
+    pub animations: Vec<OwnedAnimation>,
+    /// Textures embedded in the scene file.
+    pub textures: Vec<OwnedTexture>,
+    pub lights: Vec<OwnedLight>,
+    pub cameras: Vec<OwnedCamera>
+}
+
+impl OwnedScene {
+    /// Create a `Scene` containing a copy of this scene's data.
+    ///
+    /// The scene is validated first, see [`validate`](#method.validate). The new scene is copied
+    /// by Assimp the same way as by `Scene::clone_deep`, so it isn't associated with an importer.
+    pub fn to_scene(&self) -> Result<Scene, BuildError> {
+        try!(self.validate());
+        let raw = RawScene::new(self);
+        let mut copy = ptr::null_mut();
+        unsafe { aiCopyScene(raw.as_ptr(), &mut copy); }
+        if copy.is_null() {
+            return Err(BuildError::CopyFailed);
+        }
+        Ok(unsafe { Scene::from_copy(copy) })
+    }
+}
+
+impl<'a> From<&'a Scene> for OwnedScene {
+    fn from(scene: &'a Scene) -> OwnedScene {
+        OwnedScene {
+            flags: scene.flags.bits(),
+            root_node: From::from(scene.root_node()),
+            meshes: scene.mesh_iter().map(From::from).collect(),
+            materials: scene.material_iter().map(From::from).collect(),
+            animations: scene.animation_iter().map(From::from).collect(),
+            textures: scene.texture_iter().map(From::from).collect(),
+            lights: scene.light_iter().map(From::from).collect(),
+            cameras: scene.camera_iter().map(From::from).collect()
+        }
+    }
+}
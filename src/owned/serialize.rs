@@ -0,0 +1,34 @@
+//! `Serialize` implementations for the scene views.
+//!
+//! Views are serialized by converting them to the owned model, so the output can be deserialized
+//! as the corresponding owned type, e.g. a serialized `Scene` as an `OwnedScene`.
+
+use serde::{Serialize, Serializer};
+
+use scene::{Animation, Camera, Light, Material, Mesh, Node, Scene, Texture};
+
+use super::*;
+
+impl Serialize for Scene {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        OwnedScene::from(self).serialize(serializer)
+    }
+}
+
+macro_rules! impl_serialize_view {
+    ($name:ident, $owned:ident) => (
+        impl<'a> Serialize for $name<'a> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $owned::from(self).serialize(serializer)
+            }
+        }
+    )
+}
+
+impl_serialize_view!(Node, OwnedNode);
+impl_serialize_view!(Mesh, OwnedMesh);
+impl_serialize_view!(Material, OwnedMaterial);
+impl_serialize_view!(Animation, OwnedAnimation);
+impl_serialize_view!(Texture, OwnedTexture);
+impl_serialize_view!(Light, OwnedLight);
+impl_serialize_view!(Camera, OwnedCamera);
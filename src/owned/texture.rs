@@ -0,0 +1,53 @@
+use scene::Texture;
+
+use super::raw_slice;
+
+/// An owned copy of an embedded `Texture`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OwnedTexture {
+    /// Width of the texture in texels, or the size of `data` in bytes for compressed textures.
+    pub width: u32,
+    /// Height of the texture in texels, 0 for compressed textures.
+    pub height: u32,
+    /// The file extension of a compressed texture (e.g. `"png"`), or the channel layout of an
+    /// uncompressed texture (e.g. `"argb8888"`, only set by newer versions of Assimp).
+    pub format_hint: String,
+    /// The contents of a compressed texture file, or the texels of an uncompressed texture as
+    /// 4 bytes each in BGRA order.
+    pub data: Vec<u8>
+}
+
+impl OwnedTexture {
+    /// Returns true if the texture is stored in a compressed file format, see `format_hint`.
+    pub fn is_compressed(&self) -> bool {
+        self.height == 0
+    }
+}
+
+impl<'a, 'b> From<&'b Texture<'a>> for OwnedTexture {
+    fn from(texture: &'b Texture<'a>) -> OwnedTexture {
+        let len = if texture.height == 0 {
+            texture.width as usize
+        } else {
+            texture.width as usize * texture.height as usize * 4
+        };
+        let hint = texture.format_hint.iter()
+                                      .take_while(|&&x| x != 0)
+                                      .map(|&x| x as u8 as char)
+                                      .collect();
+
+        OwnedTexture {
+            width: texture.width,
+            height: texture.height,
+            format_hint: hint,
+            data: unsafe { raw_slice(texture.data as *const u8, len).to_vec() }
+        }
+    }
+}
+
+impl<'a> From<Texture<'a>> for OwnedTexture {
+    fn from(texture: Texture<'a>) -> OwnedTexture {
+        OwnedTexture::from(&texture)
+    }
+}
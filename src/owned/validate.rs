@@ -0,0 +1,165 @@
+use std::collections::HashSet;
+
+use ffi::{AI_MAX_NUMBER_OF_COLOR_SETS, AI_MAX_NUMBER_OF_TEXTURECOORDS, MAXLEN};
+
+use super::*;
+use super::error::ValidationError;
+use super::error::ValidationError::*;
+
+impl OwnedScene {
+    /// Checks the scene is consistent, i.e. all indices refer to existing meshes, materials and
+    /// vertices, bones and animation channels refer to existing nodes and meshes, and per-vertex
+    /// arrays have one element per vertex.
+    ///
+    /// Returns the first problem found.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        try!(validate_node(&self.root_node, self.meshes.len()));
+        for (i, mesh) in self.meshes.iter().enumerate() {
+            try!(validate_mesh(i, mesh, self.materials.len()));
+        }
+
+        let mut node_names = HashSet::new();
+        add_node_names(&self.root_node, &mut node_names);
+        let bones = self.meshes.iter().flat_map(|x| x.bones.iter().map(|x| &x.name[..]));
+        let channels = self.animations.iter().flat_map(|x| {
+            x.channels.iter().map(|x| &x.node_name[..])
+        });
+        if let Some(name) = bones.chain(channels).find(|x| !node_names.contains(x)) {
+            return Err(MissingNode { name: name.to_owned() });
+        }
+        let mesh_channels = self.animations.iter().flat_map(|x| x.mesh_channels.iter());
+        for channel in mesh_channels {
+            if self.meshes.iter().all(|x| x.name != channel.name) {
+                return Err(MissingMesh { name: channel.name.clone() });
+            }
+        }
+
+        for material in &self.materials {
+            for property in &material.properties {
+                try!(validate_name(&property.key));
+                if let MaterialPropertyData::String(ref value) = property.data {
+                    try!(validate_name(value));
+                }
+            }
+        }
+        for animation in &self.animations {
+            try!(validate_name(&animation.name));
+            for channel in &animation.channels {
+                try!(validate_name(&channel.node_name));
+            }
+            for channel in &animation.mesh_channels {
+                try!(validate_name(&channel.name));
+            }
+        }
+        for (i, texture) in self.textures.iter().enumerate() {
+            let len = if texture.is_compressed() {
+                texture.width as usize
+            } else {
+                texture.width as usize * texture.height as usize * 4
+            };
+            if texture.data.len() != len {
+                return Err(TextureSizeMismatch { texture: i });
+            }
+        }
+        for light in &self.lights {
+            try!(validate_name(&light.name));
+        }
+        for camera in &self.cameras {
+            try!(validate_name(&camera.name));
+        }
+        Ok(())
+    }
+}
+
+fn validate_node(node: &OwnedNode, num_meshes: usize) -> Result<(), ValidationError> {
+    try!(validate_name(&node.name));
+    if let Some(&index) = node.meshes.iter().find(|&&x| x as usize >= num_meshes) {
+        return Err(MeshIndexOutOfRange { node: node.name.clone(), index: index });
+    }
+    for child in &node.children {
+        try!(validate_node(child, num_meshes));
+    }
+    Ok(())
+}
+
+fn add_node_names<'a>(node: &'a OwnedNode, names: &mut HashSet<&'a str>) {
+    names.insert(&node.name);
+    for child in &node.children {
+        add_node_names(child, names);
+    }
+}
+
+fn validate_mesh(i: usize, mesh: &OwnedMesh, num_materials: usize)
+                 -> Result<(), ValidationError> {
+    let len = mesh.vertices.len();
+    try!(validate_name(&mesh.name));
+    if len == 0 {
+        return Err(EmptyMesh { mesh: i });
+    }
+    if mesh.material_index as usize >= num_materials {
+        return Err(MaterialIndexOutOfRange { mesh: i, index: mesh.material_index });
+    }
+    if mesh.colors.len() > AI_MAX_NUMBER_OF_COLOR_SETS ||
+       mesh.texture_coords.len() > AI_MAX_NUMBER_OF_TEXTURECOORDS ||
+       mesh.num_uv_components.len() != mesh.texture_coords.len() {
+        return Err(InvalidChannels { mesh: i });
+    }
+
+    try!(validate_vertex_array(i, "normals", mesh.normals.len(), len));
+    try!(validate_vertex_array(i, "tangents", mesh.tangents.len(), len));
+    try!(validate_vertex_array(i, "bitangents", mesh.bitangents.len(), len));
+    for colors in &mesh.colors {
+        try!(validate_vertex_array(i, "colors", colors.len(), len));
+    }
+    for texture_coords in &mesh.texture_coords {
+        try!(validate_vertex_array(i, "texture_coords", texture_coords.len(), len));
+    }
+    for anim_mesh in &mesh.anim_meshes {
+        if anim_mesh.colors.len() > AI_MAX_NUMBER_OF_COLOR_SETS ||
+           anim_mesh.texture_coords.len() > AI_MAX_NUMBER_OF_TEXTURECOORDS {
+            return Err(InvalidChannels { mesh: i });
+        }
+        let arrays = [&anim_mesh.vertices, &anim_mesh.normals, &anim_mesh.tangents,
+                      &anim_mesh.bitangents];
+        let channels = anim_mesh.colors.iter().map(|x| x.len())
+                                .chain(anim_mesh.texture_coords.iter().map(|x| x.len()));
+        for array_len in arrays.iter().map(|x| x.len()).chain(channels) {
+            try!(validate_vertex_array(i, "anim_meshes", array_len, len));
+        }
+    }
+
+    for (j, face) in mesh.faces.iter().enumerate() {
+        if face.is_empty() {
+            return Err(EmptyFace { mesh: i, face: j });
+        }
+        if let Some(&index) = face.iter().find(|&&x| x as usize >= len) {
+            return Err(VertexIndexOutOfRange { mesh: i, index: index });
+        }
+    }
+    for bone in &mesh.bones {
+        try!(validate_name(&bone.name));
+        if let Some(weight) = bone.weights.iter().find(|x| x.vertex_id as usize >= len) {
+            return Err(VertexIndexOutOfRange { mesh: i, index: weight.vertex_id });
+        }
+    }
+    Ok(())
+}
+
+// Missing arrays are empty
+fn validate_vertex_array(mesh: usize, array: &'static str, array_len: usize, len: usize)
+                         -> Result<(), ValidationError> {
+    if array_len != 0 && array_len != len {
+        Err(VertexCountMismatch { mesh: mesh, array: array })
+    } else {
+        Ok(())
+    }
+}
+
+// Names are stored in an `AiString`, which has a fixed size including the NUL terminator
+fn validate_name(name: &str) -> Result<(), ValidationError> {
+    if name.len() >= MAXLEN {
+        Err(NameTooLong { name: name.to_owned() })
+    } else {
+        Ok(())
+    }
+}
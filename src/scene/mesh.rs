@@ -1,5 +1,6 @@
-use ffi::{AiMesh, AiVector3D};
+use ffi::{AiMesh, AiVector3D, AI_MAX_NUMBER_OF_TEXTURECOORDS};
 
+use math::vector2::{Vector2D, Vector2DIter};
 use math::vector3::{Vector3D, Vector3DIter};
 use super::face::{Face, FaceIter};
 
@@ -20,61 +21,100 @@ impl<'a> Mesh<'a> {
         self.num_vertices
     }
 
-    pub fn vertex_iter(&self) -> Vector3DIter {
-        Vector3DIter::new(self.vertices,
-                          self.num_vertices as usize)
+    pub fn vertex_iter(&self) -> Vector3DIter<'a> {
+        unsafe { Vector3DIter::new(self.vertices,
+                                   self.num_vertices as usize) }
     }
 
     pub fn get_vertex(&self, id: u32) -> Option<Vector3D> {
         self.vertex_data(self.vertices, id)
     }
 
-    pub fn normal_iter(&self) -> Vector3DIter {
-        Vector3DIter::new(self.normals,
-                          self.num_vertices as usize)
+    pub fn normal_iter(&self) -> Vector3DIter<'a> {
+        unsafe { Vector3DIter::new(self.normals,
+                                   self.num_vertices as usize) }
     }
 
     pub fn get_normal(&self, id: u32) -> Option<Vector3D> {
         self.vertex_data(self.normals, id)
     }
 
-    pub fn tangent_iter(&self) -> Vector3DIter {
-        Vector3DIter::new(self.tangents,
-                          self.num_vertices as usize)
+    pub fn tangent_iter(&self) -> Vector3DIter<'a> {
+        unsafe { Vector3DIter::new(self.tangents,
+                                   self.num_vertices as usize) }
     }
 
     pub fn get_tangent(&self, id: u32) -> Option<Vector3D> {
         self.vertex_data(self.tangents, id)
     }
 
-    pub fn bitangent_iter(&self) -> Vector3DIter {
-        Vector3DIter::new(self.bitangents,
-                          self.num_vertices as usize)
+    pub fn bitangent_iter(&self) -> Vector3DIter<'a> {
+        unsafe { Vector3DIter::new(self.bitangents,
+                                   self.num_vertices as usize) }
     }
 
     pub fn get_bitangent(&self, id: u32) -> Option<Vector3D> {
         self.vertex_data(self.bitangents, id)
     }
 
-    pub fn texture_coords_iter(&self, channel_id: usize) -> Vector3DIter {
-        Vector3DIter::new(self.texture_coords[channel_id],
-                          self.num_vertices as usize)
+    pub fn texture_coords_iter(&self, channel_id: usize) -> Vector3DIter<'a> {
+        unsafe { Vector3DIter::new(self.texture_coords[channel_id],
+                                   self.num_vertices as usize) }
     }
 
     pub fn get_texture_coord(&self, channel_id: usize, id: u32) -> Option<Vector3D> {
         self.vertex_data(self.texture_coords[channel_id], id)
     }
 
+    /// Returns true if the mesh has texture coordinates in the given channel.
+    pub fn has_texture_coords(&self, channel_id: usize) -> bool {
+        channel_id < AI_MAX_NUMBER_OF_TEXTURECOORDS && !self.texture_coords[channel_id].is_null()
+    }
+
+    /// Returns the number of components of the texture coordinates in the given channel, e.g. 2
+    /// for UV coordinates or 3 for cube map coordinates, or 0 if the mesh doesn't have the
+    /// channel.
+    pub fn num_uv_components(&self, channel_id: usize) -> u32 {
+        if self.has_texture_coords(channel_id) {
+            self.num_uv_components[channel_id]
+        } else {
+            0
+        }
+    }
+
+    /// Returns an iterator over the texture coordinates in the given channel as 2D vectors.
+    ///
+    /// Returns `None` if the mesh doesn't have the channel, or if the coordinates have 3
+    /// components and would be truncated. Coordinates with one component have a `y` of 0.
+    pub fn texture_coords_2d_iter(&self, channel_id: usize) -> Option<Vector2DIter<'a>> {
+        if self.has_uv_coords(channel_id) {
+            unsafe { Some(Vector2DIter::new(self.texture_coords[channel_id],
+                                            self.num_vertices as usize)) }
+        } else {
+            None
+        }
+    }
+
+    /// Returns the 2D texture coordinates of a vertex, see
+    /// [`texture_coords_2d_iter`](#method.texture_coords_2d_iter).
+    pub fn get_texture_coord_2d(&self, channel_id: usize, id: u32) -> Option<Vector2D> {
+        if self.has_uv_coords(channel_id) {
+            self.vertex_data(self.texture_coords[channel_id], id).map(|x| Vector2D::new(x.x, x.y))
+        } else {
+            None
+        }
+    }
+
     pub fn num_faces(&self) -> u32 {
         self.num_faces
     }
 
-    pub fn face_iter(&self) -> FaceIter {
-        FaceIter::new(self.faces,
-                      self.num_faces as usize)
+    pub fn face_iter(&self) -> FaceIter<'a> {
+        unsafe { FaceIter::new(self.faces,
+                               self.num_faces as usize) }
     }
     
-    pub fn get_face(&self, id: u32) -> Option<Face> {
+    pub fn get_face(&self, id: u32) -> Option<Face<'a>> {
         if id < self.num_faces {
             unsafe { Some(Face::from_raw(self.faces.offset(id as isize))) }
         } else {
@@ -82,6 +122,10 @@ impl<'a> Mesh<'a> {
         }
     }
 
+    fn has_uv_coords(&self, channel_id: usize) -> bool {
+        self.has_texture_coords(channel_id) && self.num_uv_components[channel_id] <= 2
+    }
+
     #[inline]
     fn vertex_data(&self, array: *mut AiVector3D, id: u32) -> Option<Vector3D> {
         if id < self.num_vertices {
@@ -13,19 +13,19 @@ define_type_and_iterator_indirect! {
 
 impl<'a> Node<'a> {
     /// Returns the name of the node.
-    pub fn name(&self) -> &str {
-        self.name.as_ref()
+    pub fn name(&self) -> &'a str {
+        self.0.name.as_ref()
     }
 
     /// Returns the node's transformation matrix.
     pub fn transformation(&self) -> Matrix4x4 {
-        Matrix4x4::from_raw(&self.transformation)
+        unsafe { Matrix4x4::from_raw(&self.transformation) }
     }
 
     /// Return the parent of this node. Returns `None` if this node is the root node.
-    pub fn parent(&self) -> Option<Node> {
+    pub fn parent(&self) -> Option<Node<'a>> {
         if !self.parent.is_null() {
-            Some(Node::from_raw(self.parent))
+            unsafe { Some(Node::from_raw(self.parent)) }
         } else {
             None
         }
@@ -37,9 +37,9 @@ impl<'a> Node<'a> {
     }
 
     /// Returns a vector containing all of the child nodes under this node.
-    pub fn child_iter(&self) -> NodeIter {
-        NodeIter::new(self.children as *const *const AiNode,
-                      self.num_children as usize)
+    pub fn child_iter(&self) -> NodeIter<'a> {
+        unsafe { NodeIter::new(self.children as *const *const AiNode,
+                               self.num_children as usize) }
     }
 
     /// Returns the number of meshes under this node.
@@ -49,7 +49,7 @@ impl<'a> Node<'a> {
 
     /// Returns a vector containing all of the meshes under this node. These are indices into
     /// the meshes contained in the `Scene` struct.
-    pub fn meshes(&self) -> &[u32] {
+    pub fn meshes(&self) -> &'a [u32] {
         let len = self.num_meshes as usize;
         unsafe { from_raw_parts(self.meshes, len) }
     }
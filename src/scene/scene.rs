@@ -1,5 +1,10 @@
+use std::ops::Deref;
+use std::ptr;
+
 use ffi::*;
 
+use export::{self, ExportError};
+
 // Import all types
 use super::animation::*;
 use super::camera::*;
@@ -9,12 +14,167 @@ use super::mesh::*;
 use super::node::*;
 use super::texture::*;
 
-define_type! {
-    /// The `Scene` type is the root container for all imported scene data.
-    struct Scene(&AiScene)
+/// The `Scene` type is the root container for all imported scene data.
+///
+/// A `Scene` owns the data imported by Assimp, which is released when the scene is dropped. The
+/// `Node`, `Mesh` and other types returned by its methods are views which borrow from the scene,
+/// so they can't outlive it.
+///
+/// # Thread safety
+/// `Scene` is `Send` and `Sync`, and so are its views and iterators. Scenes can be imported on
+/// worker threads and handed to another thread, or shared between threads for reading.
+///
+/// # Examples
+/// A view can't be used after the scene it was taken from has been dropped:
+///
+/// ```compile_fail
+/// use assimp::Importer;
+///
+/// let importer = Importer::new();
+/// let mesh = {
+///     let scene = importer.read_file("examples/box.obj").unwrap();
+///     scene.mesh(0).unwrap()
+/// };
+/// println!("{}", mesh.num_vertices());
+/// ```
+///
+/// This also applies to views and iterators returned by other views:
+///
+/// ```compile_fail
+/// use assimp::Importer;
+///
+/// let importer = Importer::new();
+/// let children = {
+///     let scene = importer.read_file("examples/box.obj").unwrap();
+///     scene.root_node().child_iter()
+/// };
+/// println!("{}", children.count());
+/// ```
+///
+/// Nor can a scene be dropped, or passed to `Importer::apply_postprocessing`, while views of it
+/// are still alive:
+///
+/// ```compile_fail
+/// use assimp::Importer;
+///
+/// let importer = Importer::new();
+/// let scene = importer.read_file("examples/box.obj").unwrap();
+/// let root = scene.root_node();
+/// drop(scene);
+/// println!("{}", root.name());
+/// ```
+///
+/// ```compile_fail
+/// use assimp::Importer;
+///
+/// let importer = Importer::new();
+/// let scene = importer.read_file("examples/box.obj").unwrap();
+/// let vertices = scene.mesh(0).unwrap().vertex_iter();
+/// let scene = importer.apply_postprocessing(scene).unwrap();
+/// println!("{}", vertices.count());
+/// ```
+pub struct Scene {
+    raw: *const AiScene,
+    copy: bool
+}
+
+#[doc(hidden)]
+impl Scene {
+    /// Take ownership of a scene returned by one of the `aiImportFile*` functions.
+    ///
+    /// The scene is released with `aiReleaseImport` when dropped, so the caller must ensure
+    /// nothing else frees it.
+    pub unsafe fn from_raw(raw: *const AiScene) -> Scene {
+        Scene { raw: raw, copy: false }
+    }
+
+    /// Take ownership of a scene returned by `aiCopyScene`, which is released with `aiFreeScene`
+    /// when dropped.
+    pub unsafe fn from_copy(raw: *const AiScene) -> Scene {
+        Scene { raw: raw, copy: true }
+    }
+
+    pub fn to_raw(&self) -> *const AiScene {
+        self.raw
+    }
+
+    /// Returns true if the scene was created by `clone_deep` or built from an `OwnedScene`, so
+    /// isn't associated with an importer.
+    pub fn is_copy(&self) -> bool {
+        self.copy
+    }
 }
 
-impl<'a> Scene<'a> {
+// Safety: Assimp allocates a scene on the heap and doesn't keep any reference to it once the
+// import returns, so it can be released from any thread. Nothing in the scene is mutated through
+// a shared reference, only by post-processing which takes the scene by value, so concurrent reads
+// are fine too.
+unsafe impl Send for Scene {}
+unsafe impl Sync for Scene {}
+
+impl Deref for Scene {
+    type Target = AiScene;
+    fn deref(&self) -> &AiScene {
+        unsafe { &*self.raw }
+    }
+}
+
+impl Scene {
+    /// Creates a deep copy of the scene, which is independent of the original.
+    ///
+    /// This allows keeping the original scene while post-processing the copy, or the other way
+    /// around:
+    ///
+    /// ```
+    /// use assimp::Importer;
+    ///
+    /// let scene = Importer::new().read_file("examples/box.obj").unwrap();
+    /// let original = scene.clone_deep();
+    ///
+    /// let mut importer = Importer::new();
+    /// importer.triangulate(true);
+    /// let triangulated = importer.apply_postprocessing(scene).unwrap();
+    /// assert_eq!(original.mesh(0).unwrap().num_faces(), 6);
+    /// assert_eq!(triangulated.mesh(0).unwrap().num_faces(), 12);
+    /// ```
+    ///
+    /// The copy isn't associated with the importer which imported the original, so when it's
+    /// passed to `Importer::apply_postprocessing` it is processed the same way as by
+    /// `Importer::postprocess_copy`, using the properties of that importer.
+    pub fn clone_deep(&self) -> Scene {
+        let mut raw = ptr::null_mut();
+        unsafe { aiCopyScene(self.raw, &mut raw); }
+        assert!(!raw.is_null(), "failed to copy scene");
+        unsafe { Scene::from_copy(raw) }
+    }
+
+    /// Exports the scene to memory in the format with the given id, e.g. `"obj"` or `"collada"`.
+    ///
+    /// The ids of the available formats depend on which exporters Assimp was built with.
+    /// Formats which write several files, e.g. obj with a material library, only return the
+    /// main file.
+    ///
+    /// ```
+    /// use assimp::Importer;
+    ///
+    /// let scene = Importer::new().read_file("examples/box.obj").unwrap();
+    /// let data = scene.export("stl").unwrap();
+    /// assert!(!data.is_empty());
+    /// assert!(scene.export("unknown").is_err());
+    /// ```
+    pub fn export(&self, format_id: &str) -> Result<Vec<u8>, ExportError> {
+        unsafe { export::export_to_bytes(self.raw, format_id) }
+    }
+
+    /// Exports the scene to memory in Assimp's binary format.
+    ///
+    /// The format stores all of the scene data, so loading the result with
+    /// `Importer::read_assbin` gives an identical scene. This requires Assimp to be built with
+    /// the assbin exporter and importer, which are enabled by default.
+    pub fn to_assbin(&self) -> Result<Vec<u8>, ExportError> {
+        self.export("assbin")
+    }
+
     /// Returns true if the imported scene is not complete.
     pub fn is_incomplete(&self) -> bool {
         self.flags.contains(AI_SCENE_FLAGS_INCOMPLETE)
@@ -44,7 +204,7 @@ impl<'a> Scene<'a> {
 
     /// Returns the root node of the scene hierarchy
     pub fn root_node(&self) -> Node {
-        Node::from_raw(self.root_node)
+        unsafe { Node::from_raw(self.root_node) }
     }
 
     /// Returns the number of meshes in the scene.
@@ -54,8 +214,8 @@ impl<'a> Scene<'a> {
 
     /// Returns an iterator over all the meshes in the scene.
     pub fn mesh_iter(&self) -> MeshIter {
-        MeshIter::new(self.meshes as *const *const AiMesh,
-                      self.num_meshes as usize)
+        unsafe { MeshIter::new(self.meshes as *const *const AiMesh,
+                               self.num_meshes as usize) }
     }
 
     /// Return an individual mesh from the scene.
@@ -74,8 +234,8 @@ impl<'a> Scene<'a> {
 
     /// Returns an iterator over all the materials in the scene.
     pub fn material_iter(&self) -> MaterialIter {
-        MaterialIter::new(self.materials as *const *const AiMaterial,
-                          self.num_materials as usize)
+        unsafe { MaterialIter::new(self.materials as *const *const AiMaterial,
+                                   self.num_materials as usize) }
     }
 
     /// Returns the number of animations in the scene.
@@ -85,8 +245,8 @@ impl<'a> Scene<'a> {
 
     /// Returns an iterator over all the animations in the scene.
     pub fn animation_iter(&self) -> AnimationIter {
-        AnimationIter::new(self.animations as *const *const AiAnimation,
-                           self.num_animations as usize)
+        unsafe { AnimationIter::new(self.animations as *const *const AiAnimation,
+                                    self.num_animations as usize) }
     }
 
     /// Return an individual animation from the scene.
@@ -105,8 +265,8 @@ impl<'a> Scene<'a> {
 
     /// Returns an iterator over all the textures in the scene.
     pub fn texture_iter(&self) -> TextureIter {
-        TextureIter::new(self.textures as *const *const AiTexture,
-                         self.num_textures as usize)
+        unsafe { TextureIter::new(self.textures as *const *const AiTexture,
+                                  self.num_textures as usize) }
     }
 
     /// Returns the number of lights in the scene.
@@ -116,8 +276,8 @@ impl<'a> Scene<'a> {
 
     /// Returns an iterator over all the lights in the scene.
     pub fn light_iter(&self) -> LightIter {
-        LightIter::new(self.lights as *const *const AiLight,
-                       self.num_lights as usize)
+        unsafe { LightIter::new(self.lights as *const *const AiLight,
+                                self.num_lights as usize) }
     }
 
     /// Returns the number of cameras in the scene.
@@ -127,15 +287,20 @@ impl<'a> Scene<'a> {
 
     /// Returns an iterator over all the cameras in the scene.
     pub fn camera_iter(&self) -> CameraIter {
-        CameraIter::new(self.cameras as *const *const AiCamera,
-                        self.num_cameras as usize)
+        unsafe { CameraIter::new(self.cameras as *const *const AiCamera,
+                                 self.num_cameras as usize) }
     }
 }
 
 // Drop implementation for a scene owned by Assimp.
-// Scenes returned by aiImportFile* methods must be freed with aiReleaseImport.
-impl<'a> Drop for Scene<'a> {
+// Scenes returned by aiImportFile* methods must be freed with aiReleaseImport, while copies made
+// by aiCopyScene aren't owned by an importer and must be freed with aiFreeScene.
+impl Drop for Scene {
     fn drop(&mut self) {
-        unsafe { aiReleaseImport(self.0); }
+        if self.copy {
+            unsafe { aiFreeScene(self.raw); }
+        } else {
+            unsafe { aiReleaseImport(self.raw); }
+        }
     }
 }
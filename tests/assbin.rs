@@ -0,0 +1,75 @@
+extern crate assimp;
+
+use assimp::{Importer, OwnedScene, SceneBuilder, Vector3D};
+use assimp::owned::{AnimBehaviour, OwnedAnimation, OwnedMaterial, OwnedMesh, OwnedNode,
+                    OwnedNodeAnim, OwnedQuatKey, OwnedVectorKey};
+use assimp::math::Quaternion;
+
+fn assert_round_trip(scene: &OwnedScene, copy: &OwnedScene) {
+    assert_eq!(copy.root_node, scene.root_node);
+    assert_eq!(copy.meshes, scene.meshes);
+    assert_eq!(copy.materials, scene.materials);
+    assert_eq!(copy.animations, scene.animations);
+    assert_eq!(copy.textures, scene.textures);
+    assert_eq!(copy.lights, scene.lights);
+    assert_eq!(copy.cameras, scene.cameras);
+}
+
+#[test]
+fn test_assbin_round_trip() {
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/spider.obj").unwrap();
+    let data = scene.to_assbin().unwrap();
+    let copy = importer.read_assbin(&data).unwrap();
+    assert_round_trip(&OwnedScene::from(&scene), &OwnedScene::from(&copy));
+}
+
+#[test]
+fn test_assbin_round_trip_animation() {
+    let mut builder = SceneBuilder::new();
+    builder.add_material(OwnedMaterial::new("material"));
+    let vertices = vec![Vector3D::new(0.0, 0.0, 0.0),
+                        Vector3D::new(1.0, 0.0, 0.0),
+                        Vector3D::new(0.0, 1.0, 0.0)];
+    let mut node = OwnedNode::new("triangle");
+    node.meshes.push(builder.add_mesh(OwnedMesh::new("triangle", vertices, vec![vec![0, 1, 2]])));
+    builder.add_node(node);
+
+    let position = |time, x| OwnedVectorKey { time: time, value: Vector3D::new(x, 0.0, 0.0) };
+    builder.add_animation(OwnedAnimation {
+        name: "slide".to_owned(),
+        duration: 10.0,
+        ticks_per_second: 24.0,
+        channels: vec![OwnedNodeAnim {
+            node_name: "triangle".to_owned(),
+            position_keys: vec![position(0.0, 0.0), position(10.0, 5.0)],
+            rotation_keys: vec![OwnedQuatKey {
+                time: 0.0,
+                value: Quaternion::new(1.0, 0.0, 0.0, 0.0)
+            }],
+            scaling_keys: vec![OwnedVectorKey { time: 0.0, value: Vector3D::new(1.0, 1.0, 1.0) }],
+            pre_state: AnimBehaviour::Default,
+            post_state: AnimBehaviour::Repeat
+        }],
+        mesh_channels: Vec::new()
+    });
+
+    let scene = builder.build().unwrap();
+    let copy = Importer::new().read_assbin(&scene.to_assbin().unwrap()).unwrap();
+    assert_eq!(copy.num_animations(), 1);
+    assert_round_trip(&OwnedScene::from(&scene), &OwnedScene::from(&copy));
+}
+
+#[test]
+fn test_read_assbin_invalid() {
+    assert!(Importer::new().read_assbin(b"not an assbin file").is_err());
+}
+
+#[test]
+fn test_export_error() {
+    let scene = Importer::new().read_file("examples/box.obj").unwrap();
+    let err = scene.export("unknown").err().unwrap();
+    assert_eq!(err.format(), "unknown");
+    assert!(!err.message().is_empty());
+    assert!(err.to_string().contains(err.message()));
+}
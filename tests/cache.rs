@@ -0,0 +1,294 @@
+extern crate assimp;
+
+use std::env;
+use std::fs;
+use std::ptr;
+use std::slice;
+
+use assimp::{Importer, OwnedScene, SceneBuilder, Vector3D};
+use assimp::cache::{self, CacheError, CacheKey};
+use assimp::owned::{AnimBehaviour, LightSourceType, MaterialPropertyData, OwnedAnimation,
+                    OwnedBone, OwnedLight, OwnedMaterial, OwnedMesh, OwnedNode, OwnedNodeAnim,
+                    OwnedQuatKey, OwnedVectorKey, OwnedVertexWeight};
+use assimp::math::{Color3D, Matrix4x4, Quaternion};
+
+fn scene() -> OwnedScene {
+    let mut builder = SceneBuilder::new();
+    let mut material = OwnedMaterial::new("material");
+    material.set_property("$clr.diffuse", 0, 0, MaterialPropertyData::Float(vec![1.0, 0.5, 0.0]));
+    builder.add_material(material);
+
+    let vertices = vec![Vector3D::new(0.0, 0.0, 0.0),
+                        Vector3D::new(1.0, 0.0, 0.0),
+                        Vector3D::new(0.0, 1.0, 0.0)];
+    let mut mesh = OwnedMesh::new("triangle", vertices, vec![vec![0, 1, 2]]);
+    mesh.normals = vec![Vector3D::new(0.0, 0.0, 1.0); 3];
+    mesh.bones.push(OwnedBone {
+        name: "triangle".to_owned(),
+        weights: vec![OwnedVertexWeight { vertex_id: 2, weight: 0.5 }],
+        offset_matrix: Matrix4x4::identity()
+    });
+    let mut node = OwnedNode::new("triangle");
+    node.meshes.push(builder.add_mesh(mesh));
+    builder.add_node(node);
+
+    builder.add_animation(OwnedAnimation {
+        name: "animation".to_owned(),
+        duration: 2.0,
+        ticks_per_second: 25.0,
+        channels: vec![OwnedNodeAnim {
+            node_name: "triangle".to_owned(),
+            position_keys: vec![
+                OwnedVectorKey { time: 0.0, value: Vector3D::new(0.0, 0.0, 0.0) },
+                OwnedVectorKey { time: 2.0, value: Vector3D::new(1.0, 0.0, 0.0) }
+            ],
+            rotation_keys: vec![
+                OwnedQuatKey { time: 1.0, value: Quaternion::new(1.0, 0.0, 0.0, 0.0) }
+            ],
+            scaling_keys: Vec::new(),
+            pre_state: AnimBehaviour::Default,
+            post_state: AnimBehaviour::Repeat
+        }],
+        mesh_channels: Vec::new()
+    });
+
+    builder.add_light(OwnedLight {
+        name: "light".to_owned(),
+        light_type: LightSourceType::Point,
+        position: Vector3D::new(0.0, 2.0, 0.0),
+        direction: Vector3D::new(0.0, -1.0, 0.0),
+        attenuation_constant: 1.0,
+        attenuation_linear: 0.0,
+        attenuation_quadratic: 0.0,
+        color_diffuse: Color3D::new(1.0, 1.0, 1.0),
+        color_specular: Color3D::new(1.0, 1.0, 1.0),
+        color_ambient: Color3D::new(0.0, 0.0, 0.0),
+        angle_inner_cone: 0.0,
+        angle_outer_cone: 0.0
+    });
+    builder.into_owned()
+}
+
+fn saved(scene: &OwnedScene, key: CacheKey) -> Vec<u8> {
+    let mut data = Vec::new();
+    cache::save_cache(&mut data, scene, key).unwrap();
+    data
+}
+
+#[test]
+fn test_cache_round_trip() {
+    let scene = scene();
+    let data = saved(&scene, CacheKey(1));
+    assert_eq!(&data[0..8], b"AISCACHE");
+    assert_eq!(cache::load_cache(&data, CacheKey(1)).unwrap(), scene);
+    assert!(cache::cache_dependencies(&data).unwrap().is_empty());
+
+    let mut data = Vec::new();
+    cache::save_cache_with_dependencies(&mut data, &scene, CacheKey(1), &["a.mtl", "b.png"])
+        .unwrap();
+    assert_eq!(cache::cache_dependencies(&data).unwrap(), vec!["a.mtl", "b.png"]);
+    assert_eq!(cache::load_cache(&data, CacheKey(1)).unwrap(), scene);
+}
+
+#[test]
+fn test_invalid_cache() {
+    let data = saved(&scene(), CacheKey(1));
+
+    match cache::load_cache(&data, CacheKey(2)) {
+        Err(CacheError::KeyMismatch) => (),
+        x => panic!("unexpected result {:?}", x)
+    }
+
+    let mut corrupt = data.clone();
+    *corrupt.last_mut().unwrap() ^= 1;
+    match cache::load_cache(&corrupt, CacheKey(1)) {
+        Err(CacheError::ChecksumMismatch) => (),
+        x => panic!("unexpected result {:?}", x)
+    }
+
+    let mut version = data.clone();
+    version[8] = 3;
+    match cache::load_cache(&version, CacheKey(1)) {
+        Err(CacheError::UnsupportedVersion(3)) => (),
+        x => panic!("unexpected result {:?}", x)
+    }
+
+    for invalid in &[&data[..data.len() - 1], &data[..20], &data[1..], &[][..]] {
+        match cache::load_cache(invalid, CacheKey(1)) {
+            Err(CacheError::InvalidFormat) => (),
+            x => panic!("unexpected result {:?}", x)
+        }
+    }
+}
+
+// Copies `data` to `offset` bytes after an address aligned to 8 bytes, the way a cache file is
+// aligned when it's memory mapped if `offset` is zero
+fn aligned_copy(data: &[u8], offset: usize) -> Vec<u64> {
+    let mut buffer = vec![0u64; (offset + data.len() + 7) / 8];
+    unsafe {
+        let start = (buffer.as_mut_ptr() as *mut u8).add(offset);
+        ptr::copy_nonoverlapping(data.as_ptr(), start, data.len());
+    }
+    buffer
+}
+
+fn bytes(buffer: &[u64], offset: usize, len: usize) -> &[u8] {
+    let bytes = unsafe { slice::from_raw_parts(buffer.as_ptr() as *const u8, buffer.len() * 8) };
+    &bytes[offset..offset + len]
+}
+
+#[test]
+fn test_view_cache() {
+    let scene = scene();
+    let data = saved(&scene, CacheKey(1));
+    let buffer = aligned_copy(&data, 0);
+    let data = bytes(&buffer, 0, data.len());
+    let view = cache::view_cache(data, CacheKey(1)).unwrap();
+
+    let mesh = &view.meshes[0];
+    assert_eq!(mesh.name, "triangle");
+    assert_eq!(mesh.vertices, &scene.meshes[0].vertices[..]);
+    assert_eq!(mesh.normals, &scene.meshes[0].normals[..]);
+    assert_eq!(mesh.faces, vec![&[0, 1, 2][..]]);
+    assert_eq!(mesh.bones[0].weights, &scene.meshes[0].bones[0].weights[..]);
+    let channel = &view.animations[0].channels[0];
+    assert_eq!(channel.position_keys, &scene.animations[0].channels[0].position_keys[..]);
+    assert_eq!(channel.rotation_keys, &scene.animations[0].channels[0].rotation_keys[..]);
+    assert_eq!(channel.post_state, AnimBehaviour::Repeat);
+    assert_eq!(view.root_node, scene.root_node);
+    assert_eq!(view.materials, scene.materials);
+    assert_eq!(view.lights, scene.lights);
+
+    // The arrays point into the cache data rather than being copied
+    let range = data.as_ptr() as usize..data.as_ptr() as usize + data.len();
+    assert!(range.contains(&(mesh.vertices.as_ptr() as usize)));
+    assert!(range.contains(&(mesh.faces[0].as_ptr() as usize)));
+    assert!(range.contains(&(channel.position_keys.as_ptr() as usize)));
+
+    match cache::view_cache(data, CacheKey(2)) {
+        Err(CacheError::KeyMismatch) => (),
+        x => panic!("unexpected result {:?}", x)
+    }
+}
+
+#[test]
+fn test_view_cache_unaligned() {
+    let data = saved(&scene(), CacheKey(1));
+    let buffer = aligned_copy(&data, 4);
+    let unaligned = bytes(&buffer, 4, data.len());
+    match cache::view_cache(unaligned, CacheKey(1)) {
+        Err(CacheError::NotMappable) => (),
+        x => panic!("unexpected result {:?}", x)
+    }
+    assert_eq!(cache::load_cache(unaligned, CacheKey(1)).unwrap(), scene());
+}
+
+#[test]
+fn test_cache_key() {
+    let source = b"source";
+    let importer = Importer::new();
+    let key = CacheKey::new(source, &importer);
+    assert_eq!(key, CacheKey::new(source, &Importer::new()));
+    assert!(key != CacheKey::new(b"other", &importer));
+
+    let mut triangulate = Importer::new();
+    triangulate.triangulate(true);
+    assert!(key != CacheKey::new(source, &triangulate));
+
+    let mut properties = Importer::new();
+    properties.measure_time(true);
+    assert!(key != CacheKey::new(source, &properties));
+}
+
+#[test]
+fn test_read_file_cached() {
+    let path = env::temp_dir().join("assimp-rs-test-read-file-cached");
+    let path = path.to_str().unwrap();
+    let _ = fs::remove_file(path);
+
+    let mut importer = Importer::new();
+    importer.triangulate(true);
+    let imported = cache::read_file_cached(&importer, "examples/box.obj", path).unwrap();
+    let scene = importer.read_file("examples/box.obj").unwrap();
+    assert_eq!(imported, OwnedScene::from(&scene));
+
+    // The cache matches the imported scene
+    let data = fs::read(path).unwrap();
+    let dependencies = cache::cache_dependencies(&data).unwrap();
+    let key = CacheKey::from_files("examples/box.obj", &dependencies, &importer).unwrap();
+    assert_eq!(cache::load_cache(&data, key).unwrap(), imported);
+
+    // Replace the cache with a different scene using the same key, which the next read returns
+    let mut cached = imported.clone();
+    cached.root_node.name = "cached".to_owned();
+    let mut file = fs::File::create(path).unwrap();
+    cache::save_cache_with_dependencies(&mut file, &cached, key, &dependencies).unwrap();
+    drop(file);
+    assert_eq!(cache::read_file_cached(&importer, "examples/box.obj", path).unwrap(), cached);
+
+    // Changing the configuration invalidates the cache
+    let other = cache::read_file_cached(&Importer::new(), "examples/box.obj", path).unwrap();
+    assert_eq!(other.meshes[0].faces.len(), 6);
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_read_file_cached_dependencies() {
+    let dir = env::temp_dir().join("assimp-rs-test-read-file-cached-dependencies");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let obj = dir.join("cube.obj");
+    let obj = obj.to_str().unwrap();
+    let mtl = dir.join("cube.mtl");
+    let cache_file = dir.join("cube.cache");
+    let cache_file = cache_file.to_str().unwrap();
+    fs::write(obj, "mtllib cube.mtl\nusemtl red\nv 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n")
+        .unwrap();
+
+    let material_names = |scene: &OwnedScene| -> Vec<String> {
+        scene.materials.iter().filter_map(|x| x.name()).map(|x| x.to_owned()).collect()
+    };
+    let importer = Importer::new();
+    let scene = cache::read_file_cached(&importer, obj, cache_file).unwrap();
+    assert_eq!(scene.materials.len(), 1);
+
+    // Creating the missing material library invalidates the cache
+    fs::write(&mtl, "newmtl red\nKd 1 0 0\n").unwrap();
+    let scene = cache::read_file_cached(&importer, obj, cache_file).unwrap();
+    assert!(material_names(&scene).iter().any(|x| x == "red"));
+
+    // So does changing it
+    fs::write(&mtl, "newmtl green\nKd 0 1 0\n").unwrap();
+    let scene = cache::read_file_cached(&importer, obj, cache_file).unwrap();
+    assert!(material_names(&scene).iter().any(|x| x == "green"));
+    assert!(!material_names(&scene).iter().any(|x| x == "red"));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_read_file_cached_tampered() {
+    let path = env::temp_dir().join("assimp-rs-test-read-file-cached-tampered");
+    let path = path.to_str().unwrap();
+    let _ = fs::remove_file(path);
+
+    let importer = Importer::new();
+    let imported = cache::read_file_cached(&importer, "examples/box.obj", path).unwrap();
+
+    // Change a byte of the cached scene, which the checksum detects
+    let mut data = fs::read(path).unwrap();
+    let dependencies = cache::cache_dependencies(&data).unwrap();
+    let key = CacheKey::from_files("examples/box.obj", &dependencies, &importer).unwrap();
+    let offset = data.len() / 2;
+    data[offset] ^= 0xff;
+    fs::write(path, &data).unwrap();
+    match cache::load_cache(&data, key) {
+        Err(CacheError::ChecksumMismatch) => (),
+        x => panic!("unexpected result {:?}", x)
+    }
+
+    // The file is imported again, replacing the tampered cache
+    assert_eq!(cache::read_file_cached(&importer, "examples/box.obj", path).unwrap(), imported);
+    let data = fs::read(path).unwrap();
+    assert_eq!(cache::load_cache(&data, key).unwrap(), imported);
+    fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,96 @@
+extern crate assimp;
+extern crate assimp_sys;
+#[cfg(feature = "serde")]
+extern crate toml;
+
+use assimp::{ImportConfig, Importer};
+use assimp::import::LwoLayer;
+use assimp::import::structs::*;
+use assimp::math::Matrix4x4;
+use assimp_sys::*;
+
+fn full_config() -> ImportConfig {
+    let mut config = ImportConfig::default();
+    config.triangulate = true;
+    config.flip_uvs = true;
+    config.generate_normals = GenerateNormals { smooth: true, ..GenerateNormals::enabled() };
+    config.split_large_meshes = SplitLargeMeshes {
+        vertex_limit: 1000,
+        ..SplitLargeMeshes::enabled()
+    };
+    config.remove_component = RemoveComponent {
+        components: vec![ComponentType::Colors, ComponentType::Cameras],
+        ..RemoveComponent::enabled()
+    };
+    config.sort_by_primitive_type = SortByPrimitiveType {
+        remove: vec![PrimitiveType::Point, PrimitiveType::Line],
+        ..SortByPrimitiveType::enabled()
+    };
+    config.pre_transform_vertices = PreTransformVertices {
+        add_root_transformation: true,
+        root_transformation: Matrix4x4::new(1.0, 0.0, 0.0, 5.0,
+                                            0.0, 2.0, 0.0, 6.0,
+                                            0.0, 0.0, 3.0, 7.0,
+                                            0.0, 0.0, 0.0, 1.0),
+        ..PreTransformVertices::enabled()
+    };
+    config.fbx_read_lights = Some(false);
+    config.global_keyframe = Some(3);
+    config.md3_skin_name = Some("red".to_owned());
+    config.lwo_one_layer_only = Some(LwoLayer::Name("layer 1".to_owned()));
+    config
+}
+
+#[test]
+fn test_from_config() {
+    let importer = Importer::from_config(&full_config());
+    let expected = AIPROCESS_TRIANGULATE | AIPROCESS_FLIP_UVS | AIPROCESS_GEN_SMOOTH_NORMALS |
+                   AIPROCESS_SPLIT_LARGE_MESHES | AIPROCESS_REMOVE_COMPONENT |
+                   AIPROCESS_SORT_BY_PTYPE | AIPROCESS_PRE_TRANSFORM_VERTICES;
+    assert_eq!(importer.post_process_flags(), expected);
+    assert!(importer.read_file("examples/box.obj").is_ok());
+}
+
+#[test]
+fn test_default_config() {
+    let importer = Importer::from_config(&ImportConfig::default());
+    assert!(importer.post_process_flags().is_empty());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_config_toml_round_trip() {
+    for config in &[ImportConfig::default(), full_config()] {
+        let serialized = toml::to_string(config).unwrap();
+        let deserialized: ImportConfig = toml::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, *config);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_config_from_partial_toml() {
+    let config: ImportConfig = toml::from_str(r#"
+        triangulate = true
+        fbx_read_lights = false
+        lwo_one_layer_only = 2
+
+        [generate_normals]
+        enable = true
+        smooth = true
+
+        [sort_by_primitive_type]
+        enable = true
+        remove = ["Point", "Line"]
+    "#).unwrap();
+
+    assert!(config.triangulate);
+    assert_eq!(config.fbx_read_lights, Some(false));
+    assert_eq!(config.fbx_read_cameras, None);
+    assert_eq!(config.lwo_one_layer_only, Some(LwoLayer::Index(2)));
+    assert_eq!(config.generate_normals.max_smoothing_angle,
+               GenerateNormals::default().max_smoothing_angle);
+    assert_eq!(config.sort_by_primitive_type.remove,
+               vec![PrimitiveType::Point, PrimitiveType::Line]);
+    assert_eq!(config.calc_tangent_space, CalcTangentSpace::default());
+}
@@ -1,6 +1,12 @@
 extern crate assimp;
+extern crate assimp_sys;
 
-use assimp::Importer;
+use assimp::{ImportError, Importer, ImporterBuilder, SceneBuilder, Vector3D};
+use assimp::import::structs::{CalcTangentSpace, GenerateNormals, PostProcessStep, Preset,
+                              PrimitiveType, PropertyValue};
+use assimp::math::Matrix4x4;
+use assimp::owned::{OwnedBone, OwnedMaterial, OwnedMesh, OwnedNode, OwnedVertexWeight};
+use assimp_sys::*;
 
 #[test]
 fn test_get_extension_list() {
@@ -9,6 +15,24 @@ fn test_get_extension_list() {
     assert!(extensions.len() > 0);
 }
 
+#[test]
+fn test_get_importer_desc_list() {
+    let importers = Importer::get_importer_desc_list();
+    assert!(importers.len() > 0);
+
+    let obj = importers.iter().find(|x| x.handles_extension("obj"));
+    assert!(obj.is_some());
+    assert!(obj.unwrap().supports_text());
+}
+
+#[test]
+fn test_can_read() {
+    assert!(Importer::can_read("obj"));
+    assert!(Importer::can_read(".OBJ"));
+    assert!(Importer::can_read("*.obj"));
+    assert!(!Importer::can_read("not_a_format"));
+}
+
 #[test]
 fn test_import_from_file_success() {
     let importer = Importer::new();
@@ -20,7 +44,111 @@ fn test_import_from_file_success() {
 fn test_import_from_file_failure() {
     let importer = Importer::new();
     let scene = importer.read_file("examples/non_existent_file.obj");
-    assert!(scene.is_err());
+    match scene {
+        Err(ImportError::FileNotFound { ref path, .. }) => {
+            assert_eq!(path, "examples/non_existent_file.obj");
+        }
+        _ => panic!("expected ImportError::FileNotFound")
+    }
+}
+
+#[test]
+fn test_import_from_file_invalid_path() {
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/box\0.obj");
+
+    let path = "examples/box\0.obj".to_owned();
+    assert_eq!(scene.err(), Some(ImportError::InvalidPath { path: path }));
+}
+
+#[test]
+fn test_import_from_string_success() {
+    let importer = Importer::new();
+    let scene = importer.read_string(include_str!("../examples/box.obj"));
+    assert!(scene.is_ok());
+}
+
+// The buffer is allocated zeroed, so its pages are never touched
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn test_import_from_bytes_too_large() {
+    let len = u32::max_value() as usize + 1;
+    let data = vec![0u8; len];
+    let scene = Importer::new().read_bytes(&data, "obj");
+    assert_eq!(scene.err(), Some(ImportError::DataTooLarge { len: len }));
+}
+
+#[test]
+fn test_import_from_bytes_success() {
+    let importer = Importer::new();
+    let scene = importer.read_bytes(include_bytes!("../examples/box.obj"), "obj").unwrap();
+    assert_eq!(scene.num_meshes(), 1);
+}
+
+#[test]
+fn test_import_from_bytes_failure() {
+    let importer = Importer::new();
+    let scene = importer.read_bytes(&[0, 1, 2, 3, 0, 0, 255, 254], "not_a_format");
+    match scene {
+        Err(ImportError::UnsupportedFormat { ref path, .. }) => assert!(path.is_none()),
+        _ => panic!("expected ImportError::UnsupportedFormat")
+    }
+}
+
+#[test]
+fn test_import_parse_error() {
+    // Shorter than the smallest OBJ file Assimp accepts
+    let scene = Importer::new().read_bytes(b"v 0 0 0\n", "obj");
+    match scene {
+        Err(ImportError::ParseError { ref path, .. }) => assert!(path.is_none()),
+        _ => panic!("expected ImportError::ParseError")
+    }
+}
+
+#[test]
+fn test_import_postprocess_failed() {
+    // The faces of the box are all quads, so sorting removes every mesh
+    let mut importer = Importer::new();
+    importer.sort_by_primitive_type(|x| {
+        x.enable = true;
+        x.remove = vec![PrimitiveType::Polygon];
+    });
+    match importer.read_file("examples/box.obj") {
+        Err(ImportError::PostProcessFailed { ref path, .. }) => {
+            assert_eq!(path.as_ref().map(|x| &x[..]), Some("examples/box.obj"));
+        }
+        _ => panic!("expected ImportError::PostProcessFailed")
+    }
+}
+
+#[test]
+fn test_import_validation_failed() {
+    // Assimp doesn't allow two bones of a mesh to have the same name
+    let mut builder = SceneBuilder::new();
+    builder.add_material(OwnedMaterial::new("material"));
+    let vertices = vec![Vector3D::new(0.0, 0.0, 0.0),
+                        Vector3D::new(1.0, 0.0, 0.0),
+                        Vector3D::new(0.0, 1.0, 0.0)];
+    let mut mesh = OwnedMesh::new("triangle", vertices, vec![vec![0, 1, 2]]);
+    let bone = OwnedBone {
+        name: "triangle".to_owned(),
+        weights: vec![OwnedVertexWeight { vertex_id: 0, weight: 1.0 }],
+        offset_matrix: Matrix4x4::identity()
+    };
+    mesh.bones = vec![bone.clone(), bone];
+    let mut node = OwnedNode::new("triangle");
+    node.meshes.push(builder.add_mesh(mesh));
+    builder.add_node(node);
+    let scene = builder.build().unwrap();
+
+    let mut importer = Importer::new();
+    importer.validate_data_structure(true);
+    match importer.postprocess_copy(&scene) {
+        Err(ImportError::ValidationFailed { ref message, .. }) => {
+            assert!(message.starts_with("Validation failed"));
+        }
+        _ => panic!("expected ImportError::ValidationFailed")
+    }
 }
 
 #[test]
@@ -39,3 +167,109 @@ fn test_sort_by_primitive_type_panic() {
     let all = vec![Point, Line, Triangle, Polygon];
     importer.sort_by_primitive_type(|x| { x.enable = true; x.remove = all.clone() });
 }
+
+#[test]
+fn test_builder_presets() {
+    let flags = |preset| ImporterBuilder::new().preset(preset).build().post_process_flags();
+    assert_eq!(flags(Preset::ConvertToLeftHanded), AIPROCESS_CONVERT_TO_LEFT_HANDED);
+    assert_eq!(flags(Preset::TargetRealtimeFast), AIPROCESS_TARGET_REALTIME_FAST);
+    assert_eq!(flags(Preset::TargetRealtimeQuality), AIPROCESS_TARGET_REALTIME_QUALITY);
+    assert_eq!(flags(Preset::TargetRealtimeMaxQuality), AIPROCESS_TARGET_REALTIME_MAX_QUALITY);
+}
+
+#[test]
+fn test_builder_steps() {
+    let importer = ImporterBuilder::new()
+        .preset(Preset::TargetRealtimeFast)
+        .generate_normals(GenerateNormals { smooth: true, ..GenerateNormals::enabled() })
+        .calc_tangent_space(CalcTangentSpace::default())
+        .flip_uvs(true)
+        .build();
+
+    let expected = (AIPROCESS_TARGET_REALTIME_FAST | AIPROCESS_GEN_SMOOTH_NORMALS |
+                    AIPROCESS_FLIP_UVS) - AIPROCESS_GEN_NORMALS - AIPROCESS_CALC_TANGENT_SPACE;
+    assert_eq!(importer.post_process_flags(), expected);
+    assert!(importer.read_file("examples/box.obj").is_ok());
+}
+
+#[test]
+fn test_post_process_steps() {
+    let mut importer = Importer::new();
+    assert!(importer.post_process_steps().is_empty());
+
+    importer.triangulate(true);
+    importer.generate_normals(|x| { x.enable = true; x.smooth = true });
+    importer.flip_uvs(true);
+    importer.flip_uvs(false);
+
+    let steps: Vec<_> = importer.post_process_steps().into_iter().collect();
+    assert_eq!(steps, vec![PostProcessStep::Triangulate, PostProcessStep::GenerateSmoothNormals]);
+}
+
+#[test]
+fn test_properties() {
+    let mut importer = Importer::new();
+    assert!(importer.properties().is_empty());
+
+    importer.calc_tangent_space(|x| { x.enable = true; x.max_smoothing_angle = 60.0 });
+    importer.fbx_read_lights(false);
+    importer.md3_skin_name("red");
+    importer.global_keyframe(2);
+    importer.global_keyframe(3);
+
+    assert_eq!(importer.property("PP_CT_MAX_SMOOTHING_ANGLE"), Some(&PropertyValue::Float(60.0)));
+    assert_eq!(importer.property("PP_CT_TEXTURE_CHANNEL_INDEX"), Some(&PropertyValue::Int(0)));
+    assert_eq!(importer.property("IMPORT_FBX_READ_LIGHTS"), Some(&PropertyValue::Int(0)));
+    assert_eq!(importer.property("IMPORT_MD3_SKIN_NAME"),
+               Some(&PropertyValue::String("red".to_owned())));
+    assert_eq!(importer.property("IMPORT_GLOBAL_KEYFRAME"), Some(&PropertyValue::Int(3)));
+    assert_eq!(importer.property("IMPORT_MD2_KEYFRAME"), None);
+    assert_eq!(importer.properties().len(), 5);
+}
+
+#[test]
+fn test_importer_debug() {
+    let mut importer = Importer::new();
+    importer.triangulate(true);
+    importer.global_keyframe(3);
+
+    let debug = format!("{:?}", importer);
+    assert!(debug.contains("Triangulate"));
+    assert!(debug.contains("\"IMPORT_GLOBAL_KEYFRAME\": Int(3)"));
+}
+
+#[test]
+fn test_postprocess_copy() {
+    let scene = Importer::new().read_file("examples/box.obj").unwrap();
+    assert_eq!(scene.mesh(0).unwrap().num_faces(), 6);
+
+    let mut importer = Importer::new();
+    importer.triangulate(true);
+    let processed = importer.postprocess_copy(&scene).unwrap();
+
+    assert_eq!(processed.mesh(0).unwrap().num_faces(), 12);
+    assert_eq!(scene.mesh(0).unwrap().num_faces(), 6);
+}
+
+#[test]
+fn test_postprocess_copy_properties() {
+    let scene = Importer::new().read_file("examples/spider.obj").unwrap();
+    let num_meshes = scene.num_meshes();
+    assert!(scene.mesh_iter().any(|x| x.num_vertices() > 100));
+
+    let mut importer = Importer::new();
+    importer.split_large_meshes(|x| {
+        x.enable = true;
+        x.vertex_limit = 100;
+        x.triangle_limit = 100;
+    });
+
+    // The vertex limit of this importer is used when processing a copy
+    let processed = importer.postprocess_copy(&scene).unwrap();
+    assert!(processed.num_meshes() > num_meshes);
+    assert!(processed.mesh_iter().all(|x| x.num_vertices() <= 100));
+
+    // Whereas processing the scene in place uses the limit of the importer which imported it
+    let processed = importer.apply_postprocessing(scene).unwrap();
+    assert_eq!(processed.num_meshes(), num_meshes);
+}
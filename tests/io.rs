@@ -0,0 +1,206 @@
+extern crate assimp;
+
+use std::cell::Cell;
+use std::fs;
+use std::io::{Read, Seek, SeekFrom};
+use std::rc::Rc;
+
+use assimp::Importer;
+use assimp::io::{File, FileSystem, MemoryFileSystem};
+
+// File system reading from disk, counting the number of files opened and closed.
+struct DiskFileSystem {
+    opened: Rc<Cell<u32>>,
+    closed: Rc<Cell<u32>>
+}
+
+struct DiskFile {
+    file: fs::File,
+    size: u64
+}
+
+impl File for DiskFile {
+    fn read(&mut self, buf: &mut [u8]) -> usize {
+        self.file.read(buf).unwrap_or(0)
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> bool {
+        self.file.seek(pos).is_ok()
+    }
+
+    fn tell(&self) -> u64 {
+        (&self.file).seek(SeekFrom::Current(0)).unwrap_or(0)
+    }
+
+    fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+impl FileSystem for DiskFileSystem {
+    fn exists(&self, path: &str) -> bool {
+        fs::metadata(path).is_ok()
+    }
+
+    fn open(&mut self, path: &str) -> Option<Box<File>> {
+        let file = match fs::File::open(path) {
+            Ok(file) => file,
+            Err(_) => return None
+        };
+        let size = file.metadata().unwrap().len();
+        self.opened.set(self.opened.get() + 1);
+        Some(Box::new(DiskFile { file: file, size: size }))
+    }
+
+    fn close(&mut self, file: Box<File>) {
+        self.closed.set(self.closed.get() + 1);
+        drop(file);
+    }
+}
+
+fn disk_file_system() -> (DiskFileSystem, Rc<Cell<u32>>, Rc<Cell<u32>>) {
+    let opened = Rc::new(Cell::new(0));
+    let closed = Rc::new(Cell::new(0));
+    (DiskFileSystem { opened: opened.clone(), closed: closed.clone() }, opened, closed)
+}
+
+#[test]
+fn test_import_with_io_success() {
+    let (mut fs, opened, closed) = disk_file_system();
+    let importer = Importer::new();
+    {
+        let scene = importer.read_file_with_io("examples/box.obj", &mut fs).unwrap();
+        assert_eq!(scene.num_meshes(), 1);
+    }
+    assert!(opened.get() > 0);
+    assert_eq!(opened.get(), closed.get());
+}
+
+#[test]
+fn test_import_with_io_failure() {
+    let (mut fs, opened, _) = disk_file_system();
+    let importer = Importer::new();
+    let scene = importer.read_file_with_io("examples/non_existent_file.obj", &mut fs);
+    assert!(scene.is_err());
+    assert_eq!(opened.get(), 0);
+}
+
+// File system which panics when a file is opened.
+struct PanickingFileSystem;
+
+impl FileSystem for PanickingFileSystem {
+    fn exists(&self, _path: &str) -> bool {
+        true
+    }
+
+    fn open(&mut self, path: &str) -> Option<Box<File>> {
+        panic!("failed to open {}", path)
+    }
+}
+
+#[test]
+fn test_import_with_io_panic() {
+    let importer = Importer::new();
+    let scene = importer.read_file_with_io("examples/box.obj", &mut PanickingFileSystem);
+    assert!(scene.is_err());
+}
+
+// Cube referencing a material library by a path relative to the cube itself.
+const CUBE_OBJ: &'static str = "mtllib ../materials/cube.mtl
+usemtl red
+v 0 0 0
+v 1 0 0
+v 1 1 0
+v 0 1 0
+f 1 2 3 4
+";
+
+const CUBE_MTL: &'static str = "newmtl red
+Kd 1 0 0
+";
+
+#[test]
+fn test_memory_file_system_success() {
+    let mut fs = MemoryFileSystem::new();
+    fs.insert("assets/models/cube.obj", CUBE_OBJ);
+    fs.insert("assets\\materials\\cube.mtl", CUBE_MTL);
+
+    let importer = Importer::new();
+    let scene = importer.read_file_with_io("assets/models/cube.obj", &mut fs).unwrap();
+    assert_eq!(scene.num_meshes(), 1);
+    // Default material plus the material loaded from cube.mtl
+    assert_eq!(scene.num_materials(), 2);
+}
+
+#[test]
+fn test_memory_file_system_missing_reference() {
+    let mut fs = MemoryFileSystem::new();
+    fs.insert("assets/models/cube.obj", CUBE_OBJ);
+
+    let importer = Importer::new();
+    let scene = importer.read_file_with_io("assets/models/cube.obj", &mut fs).unwrap();
+    assert_eq!(scene.num_materials(), 1);
+}
+
+#[test]
+fn test_memory_file_system_failure() {
+    let mut fs = MemoryFileSystem::new();
+    let importer = Importer::new();
+    let scene = importer.read_file_with_io("assets/models/cube.obj", &mut fs);
+    assert!(scene.is_err());
+}
+
+#[test]
+fn test_memory_file_system_paths() {
+    let mut fs = MemoryFileSystem::new();
+    fs.insert("a/b/c.txt", "c");
+    assert!(fs.exists("a/b/c.txt"));
+    assert!(fs.exists("./a/b/../b/c.txt"));
+    assert!(fs.exists("a\\b\\c.txt"));
+    assert!(fs.exists("/a/b/c.txt"));
+    assert!(!fs.exists("c.txt"));
+
+    // Opening files outside of an import doesn't change how paths resolve
+    assert!(fs.open("a/b/c.txt").is_some());
+    assert!(!fs.exists("c.txt"));
+    assert_eq!(fs.get("a/b/c.txt"), Some(&b"c"[..]));
+}
+
+#[test]
+fn test_memory_file_system_separate_imports() {
+    let mut fs = MemoryFileSystem::new();
+    fs.insert("assets/models/cube.obj", CUBE_OBJ);
+    fs.insert("assets/materials/cube.mtl", CUBE_MTL);
+    fs.insert("other.obj", CUBE_OBJ.replace("../materials/cube.mtl", "cube.mtl"));
+
+    let importer = Importer::new();
+    let scene = importer.read_file_with_io("assets/models/cube.obj", &mut fs).unwrap();
+    assert_eq!(scene.num_materials(), 2);
+
+    // The missing cube.mtl isn't found in the directory of the previous import
+    let scene = importer.read_file_with_io("other.obj", &mut fs).unwrap();
+    assert_eq!(scene.num_materials(), 1);
+}
+
+#[cfg(feature = "zip")]
+#[test]
+fn test_zip_file_system_success() {
+    extern crate zip;
+
+    use std::io::{Cursor, Write};
+    use assimp::io::ZipFileSystem;
+
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options = zip::write::FileOptions::default();
+    writer.start_file("assets/models/cube.obj", options).unwrap();
+    writer.write_all(CUBE_OBJ.as_bytes()).unwrap();
+    writer.start_file("assets/materials/cube.mtl", options).unwrap();
+    writer.write_all(CUBE_MTL.as_bytes()).unwrap();
+    let archive = writer.finish().unwrap();
+
+    let mut fs = ZipFileSystem::new(Cursor::new(archive.into_inner())).unwrap();
+    let importer = Importer::new();
+    let scene = importer.read_file_with_io("assets/models/cube.obj", &mut fs).unwrap();
+    assert_eq!(scene.num_meshes(), 1);
+    assert_eq!(scene.num_materials(), 2);
+}
@@ -0,0 +1,230 @@
+extern crate assimp;
+
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::mem;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use assimp::{AttachError, ImportError, Importer, LogRecord, LogStream, Severity};
+
+#[test]
+fn test_parse_log_record() {
+    let record = LogRecord::parse("Warn,  T0: Something is odd\n");
+    assert_eq!(record.severity, Severity::Warn);
+    assert_eq!(record.message, "Something is odd");
+
+    let record = LogRecord::parse("Error, T12: Unable to open file \"a.obj\".\n");
+    assert_eq!(record.severity, Severity::Error);
+    assert_eq!(record.message, "Unable to open file \"a.obj\".");
+
+    let record = LogRecord::parse("Debug, T0: Key: value");
+    assert_eq!(record.severity, Severity::Debug);
+    assert_eq!(record.message, "Key: value");
+
+    let record = LogRecord::parse("Something, without a prefix");
+    assert_eq!(record.severity, Severity::Info);
+    assert_eq!(record.message, "Something, without a prefix");
+}
+
+#[test]
+fn test_read_file_with_log_success() {
+    let importer = Importer::new();
+    let (scene, records) = importer.read_file_with_log("examples/box.obj");
+    assert!(scene.is_ok());
+    assert!(records.iter().any(|x| x.message.contains("examples/box.obj")));
+    assert!(!records.iter().any(|x| x.severity == Severity::Error));
+}
+
+#[test]
+fn test_read_file_with_log_failure() {
+    let importer = Importer::new();
+    let (scene, records) = importer.read_file_with_log("examples/non_existent_file.obj");
+    match scene {
+        Err(ImportError::FileNotFound { ref path, .. }) => {
+            assert_eq!(path, "examples/non_existent_file.obj");
+        }
+        _ => panic!("expected ImportError::FileNotFound")
+    }
+    assert!(records.iter().any(|x| x.severity == Severity::Error));
+}
+
+#[test]
+fn test_read_file_with_log_concurrent() {
+    let files = ["examples/box.obj", "examples/spider.obj", "examples/missing.obj"];
+    let handles: Vec<_> = files.iter().map(|&file| {
+        thread::spawn(move || {
+            let importer = Importer::new();
+            let (_, records) = importer.read_file_with_log(file);
+            (file, records)
+        })
+    }).collect();
+
+    // Each import only sees messages about its own file
+    for handle in handles {
+        let (file, records) = handle.join().unwrap();
+        assert!(records.iter().any(|x| x.message.contains(file)));
+        for other in files.iter().filter(|&&x| x != file) {
+            assert!(!records.iter().any(|x| x.message.contains(other)));
+        }
+    }
+}
+
+#[test]
+fn test_callback_log_stream() {
+    let records = Arc::new(Mutex::new(Vec::new()));
+    let stream = {
+        let records = records.clone();
+        LogStream::callback(move |severity, message| {
+            records.lock().unwrap().push((severity, message.to_owned()));
+        })
+    };
+
+    let guard = stream.attach().unwrap();
+    let importer = Importer::new();
+    assert!(importer.read_file("examples/spider.obj").is_ok());
+    assert!(importer.read_file("examples/callback_missing.obj").is_err());
+    drop(guard);
+
+    let records = records.lock().unwrap();
+    assert!(records.iter().any(|x| x.0 == Severity::Info && x.1.contains("spider.obj")));
+    assert!(records.iter().any(|x| {
+        x.0 == Severity::Error && x.1.contains("callback_missing.obj")
+    }));
+    assert!(!records.iter().any(|x| x.1.ends_with('\n')));
+}
+
+#[test]
+fn test_attach_twice() {
+    let stream = LogStream::stderr();
+    assert!(!stream.attached());
+
+    let guard = stream.attach();
+    assert!(guard.is_ok());
+    assert!(stream.attached());
+    match stream.attach() {
+        Err(AttachError::AlreadyAttached) => (),
+        _ => panic!("expected AttachError::AlreadyAttached")
+    }
+
+    drop(guard);
+    assert!(!stream.attached());
+
+    // Detached streams can be attached again
+    let guard = stream.attach();
+    assert!(guard.is_ok());
+    assert!(stream.attached());
+}
+
+#[test]
+fn test_detach_order() {
+    let first = Arc::new(Mutex::new(Vec::new()));
+    let second = Arc::new(Mutex::new(Vec::new()));
+    let first_stream = {
+        let first = first.clone();
+        LogStream::callback(move |_, message| first.lock().unwrap().push(message.to_owned()))
+    };
+    let second_stream = {
+        let second = second.clone();
+        LogStream::callback(move |_, message| second.lock().unwrap().push(message.to_owned()))
+    };
+
+    let importer = Importer::new();
+    let first_guard = first_stream.attach().unwrap();
+    let second_guard = second_stream.attach().unwrap();
+    assert!(importer.read_file("examples/order_both.obj").is_err());
+
+    // Detaching the first stream leaves the second attached
+    drop(first_guard);
+    assert!(!first_stream.attached());
+    assert!(second_stream.attached());
+    assert!(importer.read_file("examples/order_second.obj").is_err());
+    drop(second_guard);
+    assert!(importer.read_file("examples/order_none.obj").is_err());
+
+    let contains = |records: &Arc<Mutex<Vec<String>>>, file: &str| {
+        records.lock().unwrap().iter().any(|x| x.contains(file))
+    };
+    assert!(contains(&first, "order_both.obj"));
+    assert!(!contains(&first, "order_second.obj"));
+    assert!(contains(&second, "order_both.obj"));
+    assert!(contains(&second, "order_second.obj"));
+    assert!(!contains(&first, "order_none.obj"));
+    assert!(!contains(&second, "order_none.obj"));
+}
+
+#[test]
+fn test_leaked_guard_detaches_on_drop() {
+    let stream = LogStream::callback(|_, _| {});
+    mem::forget(stream.attach());
+    assert!(stream.attached());
+    drop(stream);
+
+    // The stream's callback has been dropped, so must no longer be called
+    let importer = Importer::new();
+    assert!(importer.read_file("examples/leaked_missing.obj").is_err());
+}
+
+#[test]
+fn test_file_log_stream() {
+    assert!(LogStream::file("").is_none());
+    let stream = LogStream::file("missing_directory/log.txt").unwrap();
+    match stream.attach() {
+        Err(AttachError::Io(ref err)) => assert_eq!(err.kind(), io::ErrorKind::NotFound),
+        _ => panic!("expected AttachError::Io")
+    }
+    assert!(!stream.attached());
+
+    let path = env::temp_dir().join("assimp_test_file_log_stream.txt");
+    let _ = fs::remove_file(&path);
+    let stream = LogStream::file(path.to_str().unwrap()).unwrap();
+    {
+        let _guard = stream.attach().unwrap();
+        let importer = Importer::new();
+        assert!(importer.read_file("examples/file_missing.obj").is_err());
+    }
+
+    let mut contents = String::new();
+    fs::File::open(&path).unwrap().read_to_string(&mut contents).unwrap();
+    assert!(contents.contains("file_missing.obj"));
+}
+
+#[cfg(feature = "log")]
+mod facade {
+    extern crate log;
+
+    use std::sync::{Arc, Mutex};
+
+    use self::log::{Level, Log, Metadata, Record};
+    use assimp::{Importer, LogStream};
+
+    struct TestLogger(Arc<Mutex<Vec<(String, Level, String)>>>);
+
+    impl Log for TestLogger {
+        fn enabled(&self, _: &Metadata) -> bool { true }
+        fn log(&self, record: &Record) {
+            self.0.lock().unwrap().push((record.target().to_owned(),
+                                         record.level(),
+                                         record.args().to_string()));
+        }
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn test_log_facade() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        log::set_boxed_logger(Box::new(TestLogger(records.clone()))).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+
+        let stream = LogStream::log_facade();
+        let guard = stream.attach().unwrap();
+        let importer = Importer::new();
+        assert!(importer.read_file("examples/facade_missing.obj").is_err());
+        drop(guard);
+
+        let records = records.lock().unwrap();
+        assert!(records.iter().all(|x| x.0 == "assimp"));
+        assert!(records.iter().any(|x| x.1 == Level::Error && x.2.contains("facade_missing.obj")));
+    }
+}
@@ -1,6 +1,14 @@
 extern crate assimp;
 extern crate cgmath;
+#[cfg(feature = "glam")]
+extern crate glam;
 extern crate libc;
+#[cfg(feature = "mint")]
+extern crate mint;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "serde")]
+extern crate serde_json;
 use assimp::math::*;
 use libc::c_float;
 
@@ -130,3 +138,141 @@ fn test_color4_conversion() {
     let v3: [f32; 4] = v2.into();
     assert_eq!(v1, v3);
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_conversion() {
+    let v = Vector3D::new(1.0, 2.0, 3.0);
+    let q = Quaternion::new(1.0, 0.0, 0.5, 0.0);
+    let c = Color4D::new(0.25, 0.5, 0.75, 1.0);
+    let m = Matrix3x3::new(1.0, 2.0, 3.0,
+                           4.0, 5.0, 6.0,
+                           7.0, 8.0, 9.0);
+
+    assert_eq!(serde_json::to_string(&v).unwrap(), "[1.0,2.0,3.0]");
+    assert_eq!(serde_json::to_string(&q).unwrap(), "[1.0,0.0,0.5,0.0]");
+    assert_eq!(serde_json::to_string(&m).unwrap(), "[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]");
+
+    assert_eq!(serde_json::from_str::<Vector3D>("[1.0,2.0,3.0]").unwrap(), v);
+    assert_eq!(serde_json::from_str::<Quaternion>("[1.0,0.0,0.5,0.0]").unwrap(), q);
+    assert_eq!(serde_json::from_str::<Color4D>("[0.25,0.5,0.75,1.0]").unwrap(), c);
+    assert_eq!(serde_json::from_str::<Matrix3x3>(&serde_json::to_string(&m).unwrap()).unwrap(), m);
+    assert_eq!(serde_json::from_str::<Vector2D>("[1.0,2.0]").unwrap(), Vector2D::new(1.0, 2.0));
+    assert_eq!(serde_json::from_str::<Color3D>("[1.0,2.0,3.0]").unwrap(), Color3D::new(1.0, 2.0, 3.0));
+    assert!(serde_json::from_str::<Vector3D>("[1.0,2.0]").is_err());
+}
+
+// A transformation which translates by (1, 2, 3), so the conversions can be checked by
+// transforming a point
+#[cfg(any(feature = "glam", feature = "mint", feature = "nalgebra"))]
+fn translation() -> Matrix4x4 {
+    Matrix4x4::new(1.0, 0.0, 0.0, 1.0,
+                   0.0, 1.0, 0.0, 2.0,
+                   0.0, 0.0, 1.0, 3.0,
+                   0.0, 0.0, 0.0, 1.0)
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn test_mint_conversion() {
+    let v = Vector3D::new(1.0, 2.0, 3.0);
+    let q = Quaternion::new(1.0, 0.0, 0.5, 0.0);
+    let c = Color4D::new(0.25, 0.5, 0.75, 1.0);
+    let m = Matrix3x3::new(1.0, 2.0, 3.0,
+                           4.0, 5.0, 6.0,
+                           7.0, 8.0, 9.0);
+
+    let mv: mint::Vector3<f32> = v.into();
+    let mq: mint::Quaternion<f32> = q.into();
+    let mc: mint::Vector4<f32> = c.into();
+    let rows: mint::RowMatrix3<f32> = m.into();
+    let columns: mint::ColumnMatrix3<f32> = m.into();
+    assert_eq!(mv, mint::Vector3 { x: 1.0, y: 2.0, z: 3.0 });
+    assert_eq!(mq.s, 1.0);
+    assert_eq!(mc.w, 1.0);
+    assert_eq!(rows.x, mint::Vector3 { x: 1.0, y: 2.0, z: 3.0 });
+    assert_eq!(columns.x, mint::Vector3 { x: 1.0, y: 4.0, z: 7.0 });
+
+    assert_eq!(Vector3D::from(mv), v);
+    assert_eq!(Vector3D::from(mint::Point3::from(v)), v);
+    assert_eq!(Vector2D::from(mint::Vector2::from(Vector2D::new(1.0, 2.0))),
+               Vector2D::new(1.0, 2.0));
+    assert_eq!(Quaternion::from(mq), q);
+    assert_eq!(Color4D::from(mc), c);
+    assert_eq!(Color3D::from(mint::Vector3::from(Color3D::new(1.0, 0.0, 0.5))),
+               Color3D::new(1.0, 0.0, 0.5));
+    assert_eq!(Matrix3x3::from(rows), m);
+    assert_eq!(Matrix3x3::from(columns), m);
+
+    let t: mint::ColumnMatrix4<f32> = translation().into();
+    assert_eq!(t.w, mint::Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 1.0 });
+    assert_eq!(Matrix4x4::from(t), translation());
+    assert_eq!(Matrix4x4::from(mint::RowMatrix4::from(translation())), translation());
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_glam_conversion() {
+    use glam::{Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+
+    let v = Vector3D::new(1.0, 2.0, 3.0);
+    let q = Quaternion::new(1.0, 0.0, 0.5, 0.0);
+    let c = Color4D::new(0.25, 0.5, 0.75, 1.0);
+    let m = Matrix3x3::new(1.0, 2.0, 3.0,
+                           4.0, 5.0, 6.0,
+                           7.0, 8.0, 9.0);
+
+    assert_eq!(Vec3::from(v), Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(Vec3A::from(v), Vec3A::new(1.0, 2.0, 3.0));
+    assert_eq!(Quat::from(q), Quat::from_xyzw(0.0, 0.5, 0.0, 1.0));
+    assert_eq!(Vec4::from(c), Vec4::new(0.25, 0.5, 0.75, 1.0));
+    assert_eq!(Mat3::from(m).x_axis, Vec3::new(1.0, 4.0, 7.0));
+
+    assert_eq!(Vector3D::from(Vec3::from(v)), v);
+    assert_eq!(Vector3D::from(Vec3A::from(v)), v);
+    assert_eq!(Vector2D::from(Vec2::new(1.0, 2.0)), Vector2D::new(1.0, 2.0));
+    assert_eq!(Quaternion::from(Quat::from(q)), q);
+    assert_eq!(Color4D::from(Vec4::from(c)), c);
+    assert_eq!(Color3D::from(Vec3::from(Color3D::new(1.0, 0.0, 0.5))),
+               Color3D::new(1.0, 0.0, 0.5));
+    assert_eq!(Matrix3x3::from(Mat3::from(m)), m);
+
+    let t = Mat4::from(translation());
+    assert_eq!(t.transform_point3(Vec3::ZERO), Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(Matrix4x4::from(t), translation());
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn test_nalgebra_conversion() {
+    use nalgebra::{Matrix3, Matrix4, Point2, Point3, UnitQuaternion, Vector3, Vector4};
+    use nalgebra::Quaternion as NaQuaternion;
+
+    let v = Vector3D::new(1.0, 2.0, 3.0);
+    let q = Quaternion::new(1.0, 0.0, 0.5, 0.0);
+    let c = Color4D::new(0.25, 0.5, 0.75, 1.0);
+    let m = Matrix3x3::new(1.0, 2.0, 3.0,
+                           4.0, 5.0, 6.0,
+                           7.0, 8.0, 9.0);
+
+    assert_eq!(Vector3::from(v), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(Point3::from(v), Point3::new(1.0, 2.0, 3.0));
+    assert_eq!(NaQuaternion::from(q), NaQuaternion::new(1.0, 0.0, 0.5, 0.0));
+    assert_eq!(Vector4::from(c), Vector4::new(0.25, 0.5, 0.75, 1.0));
+    assert_eq!(Matrix3::from(m)[(0, 1)], 2.0);
+
+    assert_eq!(Vector3D::from(Vector3::from(v)), v);
+    assert_eq!(Vector2D::from(Point2::new(1.0, 2.0)), Vector2D::new(1.0, 2.0));
+    assert_eq!(Quaternion::from(NaQuaternion::from(q)), q);
+    assert_eq!(Color4D::from(Vector4::from(c)), c);
+    assert_eq!(Color3D::from(Vector3::from(Color3D::new(1.0, 0.0, 0.5))),
+               Color3D::new(1.0, 0.0, 0.5));
+    assert_eq!(Matrix3x3::from(Matrix3::from(m)), m);
+
+    let unit = UnitQuaternion::from(Quaternion::new(2.0, 0.0, 0.0, 0.0));
+    assert_eq!(Quaternion::from(unit), Quaternion::new(1.0, 0.0, 0.0, 0.0));
+
+    let t = Matrix4::from(translation());
+    assert_eq!(t.transform_point(&Point3::origin()), Point3::new(1.0, 2.0, 3.0));
+    assert_eq!(Matrix4x4::from(t), translation());
+}
@@ -0,0 +1,182 @@
+extern crate assimp;
+
+use std::f32::consts::PI;
+
+use assimp::math::*;
+
+const EPSILON: f32 = 1e-5;
+
+fn assert_vector_eq(a: Vector3D, b: Vector3D) {
+    assert!((a - b).length() < EPSILON, "{:?} != {:?}", a, b);
+}
+
+fn assert_quaternion_eq(a: Quaternion, b: Quaternion) {
+    // q and -q represent the same rotation
+    assert!(1.0 - a.dot(b).abs() < EPSILON, "{:?} != {:?}", a, b);
+}
+
+fn assert_matrix_eq(a: Matrix4x4, b: Matrix4x4) {
+    let a: [f32; 16] = [a.a1, a.a2, a.a3, a.a4, a.b1, a.b2, a.b3, a.b4,
+                        a.c1, a.c2, a.c3, a.c4, a.d1, a.d2, a.d3, a.d4];
+    let b: [f32; 16] = [b.a1, b.a2, b.a3, b.a4, b.b1, b.b2, b.b3, b.b4,
+                        b.c1, b.c2, b.c3, b.c4, b.d1, b.d2, b.d3, b.d4];
+    assert!(a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < EPSILON), "{:?} != {:?}", a, b);
+}
+
+fn transform() -> Matrix4x4 {
+    Matrix4x4::compose(Vector3D::new(2.0, 3.0, 4.0),
+                       Quaternion::from_axis_angle(Vector3D::new(1.0, 1.0, 0.0), 0.5),
+                       Vector3D::new(1.0, -2.0, 3.0))
+}
+
+#[test]
+fn test_vector_operations() {
+    let a = Vector3D::new(1.0, 2.0, 3.0);
+    let b = Vector3D::new(4.0, 5.0, 6.0);
+    assert_eq!(a + b, Vector3D::new(5.0, 7.0, 9.0));
+    assert_eq!(b - a, Vector3D::new(3.0, 3.0, 3.0));
+    assert_eq!(a * 2.0, Vector3D::new(2.0, 4.0, 6.0));
+    assert_eq!(b / 2.0, Vector3D::new(2.0, 2.5, 3.0));
+    assert_eq!(-a, Vector3D::new(-1.0, -2.0, -3.0));
+    assert_eq!(a.dot(b), 32.0);
+    assert_eq!(a.cross(b), Vector3D::new(-3.0, 6.0, -3.0));
+    assert_eq!(Vector3D::new(3.0, 0.0, 4.0).length(), 5.0);
+    assert_eq!(Vector3D::new(3.0, 0.0, 4.0).normalize(), Vector3D::new(0.6, 0.0, 0.8));
+    assert_eq!(Vector3D::new(0.0, 0.0, 0.0).normalize(), Vector3D::new(0.0, 0.0, 0.0));
+
+    let c = Vector2D::new(3.0, 4.0);
+    assert_eq!(c + c, c * 2.0);
+    assert_eq!(c.dot(Vector2D::new(1.0, 1.0)), 7.0);
+    assert_eq!(c.length(), 5.0);
+    assert_eq!((-c).normalize(), Vector2D::new(-0.6, -0.8));
+}
+
+#[test]
+fn test_color_operations() {
+    let a = Color4D::new(0.5, 0.25, 1.0, 1.0);
+    assert_eq!(a + a, a * 2.0);
+    assert_eq!(a - a, Color4D::new(0.0, 0.0, 0.0, 0.0));
+    assert_eq!(Color3D::new(1.0, 0.5, 0.0) / 2.0, Color3D::new(0.5, 0.25, 0.0));
+}
+
+#[test]
+fn test_matrix3_operations() {
+    let m = Matrix3x3::new(2.0, 0.0, 1.0,
+                           1.0, 3.0, 0.0,
+                           0.0, 1.0, 4.0);
+    assert_eq!(m.determinant(), 25.0);
+    assert_eq!(m.transpose().transpose(), m);
+    assert_eq!(m * Matrix3x3::identity(), m);
+    assert_eq!(m.transform_vector(Vector3D::new(1.0, 1.0, 1.0)), Vector3D::new(3.0, 4.0, 5.0));
+
+    let product = m * m.inverse().unwrap();
+    let identity = Matrix3x3::identity();
+    for (a, b) in [product.a1, product.a2, product.a3, product.b1, product.b2, product.b3,
+                   product.c1, product.c2, product.c3].iter()
+                  .zip([identity.a1, identity.a2, identity.a3, identity.b1, identity.b2,
+                        identity.b3, identity.c1, identity.c2, identity.c3].iter()) {
+        assert!((a - b).abs() < EPSILON);
+    }
+    assert!(Matrix3x3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 0.0, 1.0).inverse().is_none());
+}
+
+#[test]
+fn test_matrix4_operations() {
+    let m = transform();
+    assert_matrix_eq(m * Matrix4x4::identity(), m);
+    assert_matrix_eq(m * m.inverse().unwrap(), Matrix4x4::identity());
+    assert_matrix_eq(m.inverse().unwrap() * m, Matrix4x4::identity());
+    assert_matrix_eq(m.transpose().transpose(), m);
+    assert!((m.determinant() - 24.0).abs() < 1e-4);
+    assert!(Matrix4x4::compose(Vector3D::new(1.0, 0.0, 1.0), Quaternion::identity(),
+                               Vector3D::new(0.0, 0.0, 0.0)).inverse().is_none());
+
+    // Transforming by a product applies the right hand matrix first
+    let translation = Matrix4x4::compose(Vector3D::new(1.0, 1.0, 1.0), Quaternion::identity(),
+                                         Vector3D::new(1.0, 2.0, 3.0));
+    let scaling = Matrix4x4::compose(Vector3D::new(2.0, 2.0, 2.0), Quaternion::identity(),
+                                     Vector3D::new(0.0, 0.0, 0.0));
+    let p = Vector3D::new(1.0, 1.0, 1.0);
+    assert_eq!((translation * scaling).transform_point(p), Vector3D::new(3.0, 4.0, 5.0));
+    assert_eq!((scaling * translation).transform_point(p), Vector3D::new(4.0, 6.0, 8.0));
+    assert_eq!(translation.transform_vector(p), p);
+}
+
+#[test]
+fn test_matrix4_decompose() {
+    let scaling = Vector3D::new(2.0, 3.0, 4.0);
+    let rotation = Quaternion::from_axis_angle(Vector3D::new(1.0, 1.0, 0.0), 0.5);
+    let translation = Vector3D::new(1.0, -2.0, 3.0);
+
+    let (s, r, t) = transform().decompose();
+    assert_vector_eq(s, scaling);
+    assert_quaternion_eq(r, rotation);
+    assert_vector_eq(t, translation);
+    assert_matrix_eq(Matrix4x4::compose(s, r, t), transform());
+
+    // Mirroring is decomposed into negative scaling
+    let mirrored = Matrix4x4::compose(-scaling, rotation, translation);
+    let (s, r, t) = mirrored.decompose();
+    assert_vector_eq(s, -scaling);
+    assert_matrix_eq(Matrix4x4::compose(s, r, t), mirrored);
+
+    let (s, r, t) = Matrix4x4::identity().decompose();
+    assert_vector_eq(s, Vector3D::new(1.0, 1.0, 1.0));
+    assert_quaternion_eq(r, Quaternion::identity());
+    assert_vector_eq(t, Vector3D::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_quaternion_operations() {
+    let x = Vector3D::new(1.0, 0.0, 0.0);
+    let z = Vector3D::new(0.0, 0.0, 1.0);
+    let quarter = Quaternion::from_axis_angle(z, PI / 2.0);
+    assert_vector_eq(quarter.rotate(x), Vector3D::new(0.0, 1.0, 0.0));
+    assert_vector_eq(quarter.conjugate().rotate(x), Vector3D::new(0.0, -1.0, 0.0));
+    assert_vector_eq((quarter * quarter).rotate(x), -x);
+    assert_vector_eq(quarter.to_matrix().transform_vector(x), quarter.rotate(x));
+    assert_quaternion_eq(Quaternion::from_rotation_matrix(quarter.to_matrix()), quarter);
+    assert_eq!(Quaternion::new(2.0, 0.0, 0.0, 0.0).normalize(), Quaternion::identity());
+
+    // Rotations around different axes don't commute, the right hand rotation is applied first
+    let around_x = Quaternion::from_axis_angle(x, PI / 2.0);
+    assert_vector_eq((quarter * around_x).rotate(z), Vector3D::new(1.0, 0.0, 0.0));
+    assert_vector_eq((around_x * quarter).rotate(z), Vector3D::new(0.0, -1.0, 0.0));
+}
+
+#[test]
+fn test_quaternion_slerp() {
+    let z = Vector3D::new(0.0, 0.0, 1.0);
+    let start = Quaternion::identity();
+    let end = Quaternion::from_axis_angle(z, PI / 2.0);
+    assert_quaternion_eq(start.slerp(end, 0.0), start);
+    assert_quaternion_eq(start.slerp(end, 1.0), end);
+    assert_quaternion_eq(start.slerp(end, 0.5), Quaternion::from_axis_angle(z, PI / 4.0));
+
+    // The shortest path is used when the quaternions are in opposite hemispheres
+    let negated = Quaternion::new(-end.w, -end.x, -end.y, -end.z);
+    assert_quaternion_eq(start.slerp(negated, 0.5), Quaternion::from_axis_angle(z, PI / 4.0));
+    assert_quaternion_eq(end.slerp(end, 0.5), end);
+}
+
+#[test]
+fn test_normal_matrix() {
+    let m = transform();
+    let upper = Matrix3x3::from(m);
+    assert_vector_eq(upper.transform_vector(Vector3D::new(1.0, 2.0, 3.0)),
+                     m.transform_vector(Vector3D::new(1.0, 2.0, 3.0)));
+    assert_vector_eq(Matrix4x4::from(upper).transform_point(Vector3D::new(1.0, 2.0, 3.0)),
+                     m.transform_vector(Vector3D::new(1.0, 2.0, 3.0)));
+
+    // Non-uniform scaling changes the direction of normals differently to positions, but
+    // transformed normals stay perpendicular to transformed surfaces
+    let normal = Vector3D::new(1.0, 1.0, 0.0).normalize();
+    let tangent = Vector3D::new(1.0, -1.0, 2.0);
+    let transformed = m.normal_matrix().unwrap().transform_vector(normal).normalize();
+    assert!(transformed.dot(m.transform_vector(tangent)).abs() < EPSILON);
+    assert!(transformed.dot(m.transform_vector(normal)) > 0.0);
+
+    let flat = Matrix4x4::compose(Vector3D::new(1.0, 1.0, 0.0), Quaternion::identity(),
+                                  Vector3D::new(0.0, 0.0, 0.0));
+    assert!(flat.normal_matrix().is_none());
+}
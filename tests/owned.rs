@@ -0,0 +1,357 @@
+extern crate assimp;
+#[cfg(feature = "serde")]
+extern crate serde_json;
+
+use std::thread;
+
+use assimp::{Importer, OwnedScene, SceneBuilder, Vector3D};
+use assimp::owned::{AnimBehaviour, BuildError, OwnedAnimation, OwnedBone, OwnedMaterial,
+                    OwnedMesh, OwnedMeshAnim, OwnedNode, OwnedNodeAnim, OwnedTexture,
+                    ValidationError};
+
+fn triangle_builder() -> SceneBuilder {
+    let mut builder = SceneBuilder::new();
+    builder.add_material(OwnedMaterial::new("red"));
+
+    let vertices = vec![Vector3D::new(0.0, 0.0, 0.0),
+                        Vector3D::new(1.0, 0.0, 0.0),
+                        Vector3D::new(0.0, 1.0, 0.0)];
+    let mut mesh = OwnedMesh::new("triangle", vertices, vec![vec![0, 1, 2]]);
+    mesh.normals = vec![Vector3D::new(0.0, 0.0, 1.0); 3];
+
+    let mut node = OwnedNode::new("triangle");
+    node.meshes.push(builder.add_mesh(mesh));
+    builder.add_node(node);
+    builder
+}
+
+fn count_nodes(node: &OwnedNode) -> usize {
+    1 + node.children.iter().map(count_nodes).sum::<usize>()
+}
+
+#[test]
+fn test_owned_scene_matches_scene() {
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/spider.obj").unwrap();
+    let owned = OwnedScene::from(&scene);
+
+    assert_eq!(owned.meshes.len(), scene.num_meshes() as usize);
+    assert_eq!(owned.materials.len(), scene.num_materials() as usize);
+    assert_eq!(owned.animations.len(), scene.num_animations() as usize);
+    assert_eq!(owned.textures.len(), scene.num_textures() as usize);
+    assert_eq!(owned.lights.len(), scene.num_lights() as usize);
+    assert_eq!(owned.cameras.len(), scene.num_cameras() as usize);
+
+    assert_eq!(owned.root_node.name, scene.root_node().name());
+    assert_eq!(owned.root_node.children.len(), scene.root_node().num_children() as usize);
+
+    for (owned_mesh, mesh) in owned.meshes.iter().zip(scene.mesh_iter()) {
+        let vertices: Vec<Vector3D> = mesh.vertex_iter().collect();
+        assert_eq!(owned_mesh.vertices, vertices);
+        assert_eq!(owned_mesh.faces.len(), mesh.num_faces() as usize);
+        assert_eq!(owned_mesh.material_index, mesh.material_index);
+        assert!(owned_mesh.normals.is_empty() || owned_mesh.normals.len() == vertices.len());
+    }
+    for material in &owned.materials {
+        assert!(material.name().is_some());
+    }
+}
+
+#[test]
+fn test_owned_scene_outlives_scene() {
+    let owned = {
+        let importer = Importer::new();
+        let scene = importer.read_file("examples/box.obj").unwrap();
+        OwnedScene::from(&scene)
+    };
+
+    let mesh = &owned.meshes[0];
+    assert_eq!(mesh.faces.len(), 6);
+    for face in &mesh.faces {
+        assert_eq!(face.len(), 4);
+        assert!(face.iter().all(|&i| (i as usize) < mesh.vertices.len()));
+    }
+    assert!(mesh.vertices.contains(&Vector3D::new(-0.5, -0.5, 0.5)));
+    assert!(mesh.normals.is_empty());
+    assert!(mesh.texture_coords.is_empty());
+    assert!(count_nodes(&owned.root_node) > 1);
+}
+
+#[test]
+fn test_owned_scene_can_be_modified_and_sent() {
+    let importer = Importer::new();
+    let mut owned = OwnedScene::from(&importer.read_file("examples/box.obj").unwrap());
+    for vertex in &mut owned.meshes[0].vertices {
+        *vertex = Vector3D::new(vertex.x * 2.0, vertex.y * 2.0, vertex.z * 2.0);
+    }
+
+    let copy = owned.clone();
+    let vertices = thread::spawn(move || owned.meshes[0].vertices.clone()).join().unwrap();
+    assert!(vertices.iter().all(|v| v.x.abs() == 1.0 && v.y.abs() == 1.0 && v.z.abs() == 1.0));
+    assert_eq!(copy.meshes[0].vertices, vertices);
+}
+
+#[test]
+fn test_scene_builder() {
+    let builder = triangle_builder();
+    let scene = builder.build().unwrap();
+    assert_eq!(scene.num_meshes(), 1);
+    assert_eq!(scene.num_materials(), 1);
+    assert_eq!(scene.root_node().name(), "root");
+
+    let owned = OwnedScene::from(&scene);
+    assert_eq!(owned, builder.into_owned());
+
+    let mut importer = Importer::new();
+    importer.gen_uv_coords(true);
+    importer.calc_tangent_space(|x| x.enable = true);
+    assert!(importer.apply_postprocessing(scene).is_ok());
+}
+
+#[test]
+fn test_owned_scene_to_scene() {
+    let importer = Importer::new();
+    let owned = OwnedScene::from(&importer.read_file("examples/spider.obj").unwrap());
+    let scene = owned.to_scene().unwrap();
+    assert!(scene.is_copy());
+
+    let copy = OwnedScene::from(&scene);
+    assert_eq!(copy.meshes, owned.meshes);
+    assert_eq!(copy.materials, owned.materials);
+    assert_eq!(copy.root_node, owned.root_node);
+}
+
+#[test]
+fn test_validate() {
+    let valid = triangle_builder().into_owned();
+    assert_eq!(valid.validate(), Ok(()));
+
+    let mut scene = valid.clone();
+    scene.root_node.children[0].meshes.push(1);
+    assert_eq!(scene.validate(), Err(ValidationError::MeshIndexOutOfRange {
+        node: "triangle".to_owned(),
+        index: 1
+    }));
+
+    let mut scene = valid.clone();
+    scene.meshes[0].material_index = 1;
+    assert_eq!(scene.validate(),
+               Err(ValidationError::MaterialIndexOutOfRange { mesh: 0, index: 1 }));
+
+    let mut scene = valid.clone();
+    scene.meshes[0].faces.push(vec![0, 3]);
+    assert_eq!(scene.validate(),
+               Err(ValidationError::VertexIndexOutOfRange { mesh: 0, index: 3 }));
+
+    let mut scene = valid.clone();
+    scene.meshes[0].normals.pop();
+    assert_eq!(scene.validate(),
+               Err(ValidationError::VertexCountMismatch { mesh: 0, array: "normals" }));
+
+    let mut scene = valid.clone();
+    scene.meshes[0].texture_coords.push(vec![Vector3D::new(0.0, 0.0, 0.0); 3]);
+    assert_eq!(scene.validate(), Err(ValidationError::InvalidChannels { mesh: 0 }));
+
+    let mut scene = valid.clone();
+    scene.textures.push(OwnedTexture {
+        width: 2,
+        height: 2,
+        format_hint: String::new(),
+        data: vec![0; 8]
+    });
+    assert_eq!(scene.validate(), Err(ValidationError::TextureSizeMismatch { texture: 0 }));
+
+    let mut scene = valid.clone();
+    scene.root_node.name = "x".repeat(1024);
+    assert!(match scene.validate() {
+        Err(ValidationError::NameTooLong { .. }) => true,
+        _ => false
+    });
+    match scene.to_scene() {
+        Err(BuildError::Invalid(ValidationError::NameTooLong { .. })) => {}
+        _ => panic!("expected BuildError::Invalid")
+    }
+}
+
+// root -> a -> (b, c), with a mesh in each node, an animated bone attached to b and an animation
+// of mesh b
+fn hierarchy() -> OwnedScene {
+    let mut builder = SceneBuilder::new();
+    builder.add_material(OwnedMaterial::new("first"));
+    builder.add_material(OwnedMaterial::new("second"));
+
+    let vertices = vec![Vector3D::new(0.0, 0.0, 0.0); 3];
+    let mut nodes = Vec::new();
+    for (i, name) in ["a", "b", "c"].iter().enumerate() {
+        let mut mesh = OwnedMesh::new(name, vertices.clone(), vec![vec![0, 1, 2]]);
+        mesh.material_index = i as u32 % 2;
+        let mut node = OwnedNode::new(name);
+        node.meshes.push(builder.add_mesh(mesh));
+        nodes.push(node);
+    }
+    let c = nodes.pop().unwrap();
+    let b = nodes.pop().unwrap();
+    let mut a = nodes.pop().unwrap();
+    a.children.push(b);
+    a.children.push(c);
+    builder.add_node(a);
+
+    let mut owned = builder.into_owned();
+    owned.meshes[0].bones.push(OwnedBone {
+        name: "b".to_owned(),
+        weights: Vec::new(),
+        offset_matrix: owned.root_node.transformation
+    });
+    owned.animations.push(OwnedAnimation {
+        name: "animation".to_owned(),
+        duration: 1.0,
+        ticks_per_second: 25.0,
+        channels: vec![OwnedNodeAnim {
+            node_name: "b".to_owned(),
+            position_keys: Vec::new(),
+            rotation_keys: Vec::new(),
+            scaling_keys: Vec::new(),
+            pre_state: AnimBehaviour::Default,
+            post_state: AnimBehaviour::Default
+        }],
+        mesh_channels: vec![OwnedMeshAnim { name: "b".to_owned(), keys: Vec::new() }]
+    });
+    owned
+}
+
+#[test]
+fn test_rename_node() {
+    let mut scene = hierarchy();
+    assert_eq!(scene.rename_node("b", "bone"), Ok(()));
+    assert_eq!(scene.rename_node("b", "other"),
+               Err(ValidationError::MissingNode { name: "b".to_owned() }));
+
+    assert!(scene.find_node("b").is_none());
+    assert_eq!(scene.find_node("bone").unwrap().meshes, vec![1]);
+    assert_eq!(scene.meshes[0].bones[0].name, "bone");
+    assert_eq!(scene.animations[0].channels[0].node_name, "bone");
+    assert_eq!(scene.validate(), Ok(()));
+
+    scene.find_node_mut("bone").unwrap().name = "renamed".to_owned();
+    assert_eq!(scene.validate(), Err(ValidationError::MissingNode { name: "bone".to_owned() }));
+}
+
+#[test]
+fn test_rename_node_duplicate() {
+    let mut scene = hierarchy();
+    assert_eq!(scene.rename_node("b", "c"),
+               Err(ValidationError::DuplicateNode { name: "c".to_owned() }));
+    assert_eq!(scene, hierarchy());
+
+    // The bone could refer to either node called "c", so neither can be renamed
+    scene.find_node_mut("b").unwrap().name = "c".to_owned();
+    assert_eq!(scene.rename_node("c", "d"),
+               Err(ValidationError::DuplicateNode { name: "c".to_owned() }));
+    assert!(scene.find_node("d").is_none());
+
+    assert_eq!(hierarchy().rename_node("a", "a"), Ok(()));
+}
+
+#[test]
+fn test_reparent_node() {
+    let mut scene = hierarchy();
+    let invalid = |node: &str, parent: &str| {
+        Err(ValidationError::InvalidParent { node: node.to_owned(), parent: parent.to_owned() })
+    };
+    assert_eq!(scene.reparent_node("a", "c"), invalid("a", "c"));
+    assert_eq!(scene.reparent_node("a", "a"), invalid("a", "a"));
+    assert_eq!(scene.reparent_node("root", "a"), invalid("root", "a"));
+    assert_eq!(scene.reparent_node("missing", "a"),
+               Err(ValidationError::MissingNode { name: "missing".to_owned() }));
+    assert_eq!(scene.reparent_node("c", "missing"),
+               Err(ValidationError::MissingNode { name: "missing".to_owned() }));
+    assert_eq!(scene, hierarchy());
+
+    assert_eq!(scene.reparent_node("c", "b"), Ok(()));
+    assert_eq!(scene.reparent_node("b", "root"), Ok(()));
+    let names: Vec<&str> = scene.root_node.children.iter().map(|x| &x.name[..]).collect();
+    assert_eq!(names, vec!["a", "b"]);
+    assert!(scene.find_node("a").unwrap().children.is_empty());
+    assert_eq!(scene.find_node("b").unwrap().children[0].name, "c");
+}
+
+#[test]
+fn test_remove_mesh() {
+    let mut scene = hierarchy();
+    let mesh = scene.remove_mesh(1).unwrap();
+    assert_eq!(mesh.name, "b");
+    assert_eq!(scene.meshes.len(), 2);
+    assert_eq!(scene.find_node("a").unwrap().meshes, vec![0]);
+    assert!(scene.find_node("b").unwrap().meshes.is_empty());
+    assert_eq!(scene.find_node("c").unwrap().meshes, vec![1]);
+    assert!(scene.animations[0].mesh_channels.is_empty());
+}
+
+#[test]
+fn test_remove_mesh_with_shared_name() {
+    let mut scene = hierarchy();
+    scene.meshes[2].name = "b".to_owned();
+    assert!(scene.remove_mesh(1).is_ok());
+    assert_eq!(scene.animations[0].mesh_channels[0].name, "b");
+}
+
+#[test]
+fn test_missing_mesh() {
+    let mut scene = hierarchy();
+    scene.meshes[1].name = "renamed".to_owned();
+    assert_eq!(scene.validate(), Err(ValidationError::MissingMesh { name: "b".to_owned() }));
+}
+
+#[test]
+fn test_remove_material() {
+    let mut scene = hierarchy();
+    assert_eq!(scene.reassign_material(0, 2),
+               Err(ValidationError::MaterialIndexOutOfRange { mesh: 0, index: 2 }));
+    assert_eq!(scene.reassign_material(0, 1), Ok(()));
+    assert!(scene.meshes.iter().all(|x| x.material_index == 1));
+
+    let material = scene.remove_material(0).unwrap();
+    assert_eq!(material.name(), Some("first"));
+    assert!(scene.meshes.iter().all(|x| x.material_index == 0));
+}
+
+#[test]
+fn test_remove_used_material() {
+    let mut scene = hierarchy();
+    assert_eq!(scene.remove_material(1),
+               Err(ValidationError::MaterialInUse { index: 1, mesh: 1 }));
+    assert_eq!(scene, hierarchy());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_owned_scene_json_round_trip() {
+    let mut scene = hierarchy();
+    scene.textures.push(OwnedTexture {
+        width: 1,
+        height: 1,
+        format_hint: String::new(),
+        data: vec![255, 0, 0, 255]
+    });
+
+    let json = serde_json::to_string(&scene).unwrap();
+    let deserialized: OwnedScene = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, scene);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_scene_serializes_as_owned_scene() {
+    let scene = Importer::new().read_file("examples/spider.obj").unwrap();
+    let owned = OwnedScene::from(&scene);
+
+    assert_eq!(serde_json::to_value(&scene).unwrap(), serde_json::to_value(&owned).unwrap());
+    assert_eq!(serde_json::to_value(&scene.root_node()).unwrap(),
+               serde_json::to_value(&owned.root_node).unwrap());
+    assert_eq!(serde_json::to_value(&scene.mesh(0).unwrap()).unwrap(),
+               serde_json::to_value(&owned.meshes[0]).unwrap());
+
+    let json = serde_json::to_string(&scene).unwrap();
+    let deserialized: OwnedScene = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, owned);
+}
@@ -13,3 +13,62 @@ fn test_scene_properties() {
     assert_eq!(scene.num_lights() as usize, scene.light_iter().len());
     assert_eq!(scene.num_cameras() as usize, scene.camera_iter().len());
 }
+
+#[test]
+fn test_views_borrow_from_scene() {
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/box.obj").unwrap();
+
+    // Views returned by other views are tied to the scene, not to the intermediate view
+    let vertices = scene.mesh(0).unwrap().vertex_iter();
+    let names: Vec<&str> = scene.root_node().child_iter().map(|x| x.name()).collect();
+    assert_eq!(vertices.count(), scene.mesh(0).unwrap().num_vertices() as usize);
+    assert_eq!(names.len(), scene.root_node().num_children() as usize);
+
+    // The scene can be moved once all views have been dropped
+    let scene = importer.apply_postprocessing(scene).unwrap();
+    assert!(scene.root_node().parent().is_none());
+}
+
+#[test]
+fn test_clone_deep() {
+    let scene = Importer::new().read_file("examples/spider.obj").unwrap();
+    let copy = scene.clone_deep();
+    assert!(copy.is_copy() && !scene.is_copy());
+    assert_eq!(copy.num_meshes(), scene.num_meshes());
+    assert_eq!(copy.num_materials(), scene.num_materials());
+    assert_eq!(copy.root_node().name(), scene.root_node().name());
+    for (a, b) in copy.mesh_iter().zip(scene.mesh_iter()) {
+        assert_eq!(a.num_vertices(), b.num_vertices());
+        assert!(a.vertex_iter().zip(b.vertex_iter()).all(|(a, b)| *a == *b));
+    }
+
+    // The copy outlives the original, and can be post-processed separately
+    drop(scene);
+    let copy_of_copy = copy.clone_deep();
+    let mut importer = Importer::new();
+    importer.triangulate(true);
+    let processed = importer.apply_postprocessing(copy).unwrap();
+    assert!(!processed.is_copy());
+    assert!(processed.mesh_iter().flat_map(|x| x.face_iter()).all(|x| x.num_indices <= 3));
+    assert_eq!(copy_of_copy.num_meshes(), processed.num_meshes());
+}
+
+#[test]
+fn test_texture_coords_2d() {
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/spider.obj").unwrap();
+    let mesh = scene.mesh_iter().find(|x| x.has_texture_coords(0)).unwrap();
+    assert_eq!(mesh.num_uv_components(0), 2);
+    assert_eq!(mesh.num_uv_components(1), 0);
+    assert!(mesh.texture_coords_2d_iter(1).is_none());
+    assert!(mesh.texture_coords_2d_iter(100).is_none());
+
+    let uvs = mesh.texture_coords_2d_iter(0).unwrap();
+    assert_eq!(uvs.len(), mesh.num_vertices() as usize);
+    for (i, (uv, uvw)) in uvs.zip(mesh.texture_coords_iter(0)).enumerate() {
+        assert_eq!((uv.x, uv.y), (uvw.x, uvw.y));
+        assert_eq!(mesh.get_texture_coord_2d(0, i as u32), Some(uv));
+    }
+    assert_eq!(mesh.get_texture_coord_2d(0, mesh.num_vertices()), None);
+}
@@ -0,0 +1,98 @@
+extern crate assimp;
+
+use std::sync::Arc;
+use std::thread;
+
+use assimp::{Importer, Mesh, Node, Scene};
+use assimp::math::Vector3D;
+
+const FILES: &'static [&'static str] = &[
+    "examples/box.obj",
+    "examples/spider.obj",
+    "examples/box.obj",
+    "examples/spider.obj"
+];
+
+/// Vertex positions of every mesh in a scene, for comparing imports.
+fn vertices(scene: &Scene) -> Vec<Vec<[f32; 3]>> {
+    scene.mesh_iter()
+         .map(|mesh| mesh.vertex_iter().map(|v| [v.x, v.y, v.z]).collect())
+         .collect()
+}
+
+fn import(file: &str) -> Scene {
+    let mut importer = Importer::new();
+    importer.triangulate(true);
+    importer.read_file(file).unwrap()
+}
+
+#[test]
+fn test_scene_types_are_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Scene>();
+    assert_send_sync::<Mesh>();
+    assert_send_sync::<Node>();
+    assert_send_sync::<Vector3D>();
+
+    fn assert_send<T: Send>() {}
+    assert_send::<Importer>();
+}
+
+#[test]
+fn test_concurrent_imports() {
+    let expected: Vec<_> = FILES.iter().map(|file| vertices(&import(file))).collect();
+
+    // Import on separate threads, handing the scenes back to this thread
+    let handles: Vec<_> = FILES.iter()
+                               .map(|&file| thread::spawn(move || import(file)))
+                               .collect();
+    let scenes: Vec<Scene> = handles.into_iter().map(|x| x.join().unwrap()).collect();
+
+    for (scene, expected) in scenes.iter().zip(expected.iter()) {
+        assert_eq!(vertices(scene), *expected);
+    }
+}
+
+#[test]
+fn test_shared_scene() {
+    let scene = Arc::new(import("examples/spider.obj"));
+    let expected = vertices(&scene);
+
+    let handles: Vec<_> = (0..4).map(|_| {
+        let scene = scene.clone();
+        thread::spawn(move || vertices(&scene))
+    }).collect();
+
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_importer_moved_to_thread() {
+    let mut importer = Importer::new();
+    importer.triangulate(true);
+
+    let scene = thread::spawn(move || importer.read_file("examples/box.obj").unwrap())
+                    .join()
+                    .unwrap();
+    assert_eq!(vertices(&scene), vertices(&import("examples/box.obj")));
+}
+
+#[test]
+fn test_concurrent_import_errors() {
+    // Each failed import reports its own error, even when other imports fail at the same time
+    let handles: Vec<_> = (0..8).map(|i| {
+        thread::spawn(move || {
+            let file = format!("examples/non_existent_file_{}.obj", i);
+            let err = Importer::new().read_file(&file).err().unwrap();
+            (file, err)
+        })
+    }).collect();
+
+    for handle in handles {
+        let (file, err) = handle.join().unwrap();
+        assert_eq!(err.path(), Some(&file[..]));
+        assert!(err.message().contains(&file), "{:?}", err);
+    }
+}